@@ -0,0 +1,4 @@
+// Generates a test for every example in examples/2019, see aoc_core::examples
+fn main() {
+    aoc_core::examples::generate_tests(2019, "../examples/2019");
+}
@@ -1,26 +1,32 @@
-use aoc_core::AoCResult;
-use std::str::FromStr;
+use aoc_core::{AoCError, AoCResult};
+use aoc_core::aoc_error::parse_at;
 
-aoc_core::make_day!(Day01);
+aoc_core::make_day!(Day01, 2019, 1);
 
-pub fn solve_part1(input: &String) -> AoCResult {
-    let res: u64 = input
-        .lines()
-        .map(|l| u64::from_str(l).unwrap())
+pub fn solve_part1(input: &str) -> Result<AoCResult, AoCError> {
+    let res: u64 = get_masses(input)?
+        .into_iter()
         .map(get_fuel)
         .sum();
 
-    AoCResult::Num(res)
+    Ok(AoCResult::Num(res))
 }
 
-pub fn solve_part2(input: &String) -> AoCResult {
-    let res: u64 = input
-        .lines()
-        .map(|l| u64::from_str(l).unwrap())
+pub fn solve_part2(input: &str) -> Result<AoCResult, AoCError> {
+    let res: u64 = get_masses(input)?
+        .into_iter()
         .map(get_fuel_recursive)
         .sum();
 
-    AoCResult::Num(res)
+    Ok(AoCResult::Num(res))
+}
+
+fn get_masses(input: &str) -> Result<Vec<u64>, AoCError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, l)| parse_at(l.trim_end(), i + 1, 1))
+        .collect()
 }
 
 fn get_fuel(mass: u64) -> u64 {
@@ -43,51 +49,58 @@ mod tests {
     #[test]
     fn part1_example1() {
         let input = String::from("12");
-        let result = solve_part1(&input);
-        assert_eq!(u64::from(result), 2);
+        let result = solve_part1(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 2);
     }
 
     #[test]
     fn part1_example2() {
         let input = String::from("14");
-        let result = solve_part1(&input);
-        assert_eq!(u64::from(result), 2);
+        let result = solve_part1(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 2);
     }
 
     #[test]
 
     fn part1_example3() {
         let input = String::from("1969");
-        let result = solve_part1(&input);
-        assert_eq!(u64::from(result), 654);
+        let result = solve_part1(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 654);
     }
 
     #[test]
     fn part1_example4() {
         let input = String::from("100756");
-        let result = solve_part1(&input);
-        assert_eq!(u64::from(result), 33583);
+        let result = solve_part1(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 33583);
     }
 
     #[test]
     fn part2_example1() {
         let input = String::from("14");
-        let result = solve_part2(&input);
-        assert_eq!(u64::from(result), 2);
+        let result = solve_part2(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 2);
     }
 
     #[test]
 
     fn part2_example2() {
         let input = String::from("1969");
-        let result = solve_part2(&input);
-        assert_eq!(u64::from(result), 966);
+        let result = solve_part2(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 966);
     }
 
     #[test]
     fn part2_example3() {
         let input = String::from("100756");
-        let result = solve_part2(&input);
-        assert_eq!(u64::from(result), 50346);
+        let result = solve_part2(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 50346);
+    }
+
+    #[test]
+    fn part1_invalid_input() {
+        let input = String::from("12\r\n1x4");
+        let result = solve_part1(&input);
+        assert!(matches!(result, Err(AoCError::ParseError { line: 2, column: 1, .. })));
     }
 }
@@ -1,22 +1,24 @@
-use aoc_core::AoCResult;
+use aoc_core::{AoCError, AoCResult, PuzzleInput};
 use crate::shared::intcode::{IntcodeProgram, RunMode};
 
-aoc_core::make_day!(Day02);
+aoc_core::make_day!(Day02, 2019, 2, parsed: IntcodeProgram);
 
-pub fn solve_part1(input: &String) -> AoCResult {
-    let mut prgm = IntcodeProgram::new(input, None);
+pub fn parse(input: &PuzzleInput) -> Result<IntcodeProgram, AoCError> {
+    IntcodeProgram::new(input, None)
+}
+
+pub fn solve_part1(prgm: &IntcodeProgram) -> Result<AoCResult, AoCError> {
+    let mut prgm = prgm.clone();
 
     // Do replacements and run program:
     prgm.write(1, 12);
     prgm.write(2, 2);
     prgm.run(RunMode::Free);
 
-    AoCResult::Num(u64::try_from(prgm.read(0)).unwrap())
+    Ok(AoCResult::Int(prgm.read(0)))
 }
 
-pub fn solve_part2(input: &String) -> AoCResult {
-    let prgm = IntcodeProgram::new(input, None);
-
+pub fn solve_part2(prgm: &IntcodeProgram) -> Result<AoCResult, AoCError> {
     // Try replacements until output 19690720 is found
     for noun in 0..99 {
         for verb in 0..99 {
@@ -27,12 +29,12 @@ pub fn solve_part2(input: &String) -> AoCResult {
 
             prgm_cpy.run(RunMode::Free);
             if prgm_cpy.read(0) == 19690720 {
-                return AoCResult::Num(u64::try_from(100 * noun + verb).unwrap());
+                return Ok(AoCResult::Int(100 * noun + verb));
             }
         }
     }
 
-    panic!("No solution found");
+    Err(AoCError::NoSolution)
 }
 
 #[cfg(test)]
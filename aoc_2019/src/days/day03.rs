@@ -1,15 +1,12 @@
-use aoc_core::AoCResult;
+use aoc_core::{AoCError, AoCResult};
+use aoc_core::aoc_error::{column_of, parse_at};
 use crate::shared::geometry::Point;
 use itertools::Itertools;
-use std::str::FromStr;
 
-aoc_core::make_day!(Day03);
+aoc_core::make_day!(Day03, 2019, 3);
 
-pub fn solve_part1(input: &String) -> AoCResult {
-    let (wire1, wire2) = input.lines().next_tuple().unwrap();
-
-    let wire1_parts = parse_wire_segments(wire1);
-    let wire2_parts = parse_wire_segments(wire2);
+pub fn solve_part1(input: &str) -> Result<AoCResult, AoCError> {
+    let (wire1_parts, wire2_parts) = parse_wires(input)?;
 
     let mut intersections = Vec::<Point>::new();
     for w1 in wire1_parts.iter() {
@@ -26,16 +23,13 @@ pub fn solve_part1(input: &String) -> AoCResult {
         .filter(|p| p.x != 0 && p.y != 0)
         .map(|p| p.x.abs() + p.y.abs())
         .min()
-        .unwrap();
+        .ok_or(AoCError::NoSolution)?;
 
-    AoCResult::Num(u64::try_from(closest).unwrap())
+    Ok(AoCResult::Num(u64::try_from(closest).unwrap()))
 }
 
-pub fn solve_part2(input: &String) -> AoCResult {
-    let (wire1, wire2) = input.lines().next_tuple().unwrap();
-
-    let wire1_parts = parse_wire_segments(wire1);
-    let wire2_parts = parse_wire_segments(wire2);
+pub fn solve_part2(input: &str) -> Result<AoCResult, AoCError> {
+    let (wire1_parts, wire2_parts) = parse_wires(input)?;
 
     let mut intersections = Vec::<(Point, u64)>::new();
 
@@ -63,9 +57,9 @@ pub fn solve_part2(input: &String) -> AoCResult {
         .filter(|(p, _)| p.x != 0 && p.y != 0)
         .map(|(_, dist)| *dist)
         .min()
-        .unwrap();
+        .ok_or(AoCError::NoSolution)?;
 
-    AoCResult::Num(u64::try_from(smallest_dist).unwrap())
+    Ok(AoCResult::Num(smallest_dist))
 }
 
 #[derive(Debug)]
@@ -114,11 +108,24 @@ impl WireSegment {
     }
 }
 
-fn parse_wire_segments(wire: &str) -> Vec<WireSegment> {
+fn parse_wires(input: &str) -> Result<(Vec<WireSegment>, Vec<WireSegment>), AoCError> {
+    let (wire1, wire2) = input
+        .lines()
+        .next_tuple()
+        .ok_or_else(|| AoCError::parse_error(1, 1, "expected two wires"))?;
+
+    Ok((parse_wire_segments(wire1.trim_end(), 1)?, parse_wire_segments(wire2.trim_end(), 2)?))
+}
+
+fn parse_wire_segments(wire: &str, line: usize) -> Result<Vec<WireSegment>, AoCError> {
     let mut p = Point { x: 0, y: 0 };
     wire.split(',')
-        .map(|s| (s.chars().nth(0).unwrap(), i64::from_str(&s[1..]).unwrap()))
-        .map(|w| {
+        .map(|s| {
+            let dir = s.chars().next().ok_or_else(|| AoCError::parse_error(line, column_of(wire, s), "empty wire segment"))?;
+            let len: i64 = parse_at(&s[dir.len_utf8()..], line, column_of(wire, s) + 1)?;
+            Ok((dir, len))
+        })
+        .map_ok(|w| {
             let next_point = match w.0 {
                 'U' => Point {
                     x: p.x,
@@ -144,7 +151,7 @@ fn parse_wire_segments(wire: &str) -> Vec<WireSegment> {
                 end: next_point,
             };
             p = next_point;
-            return seg;
+            seg
         })
         .collect()
 }
@@ -156,42 +163,42 @@ mod tests {
     #[test]
     fn part1_example1() {
         let input = String::from("R8,U5,L5,D3\r\nU7,R6,D4,L4");
-        let result = solve_part1(&input);
-        assert_eq!(u64::from(result), 6);
+        let result = solve_part1(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 6);
     }
 
     #[test]
     fn part1_example2() {
 		let input = String::from("R75,D30,R83,U83,L12,D49,R71,U7,L72\r\nU62,R66,U55,R34,D71,R55,D58,R83");
-        let result = solve_part1(&input);
-        assert_eq!(u64::from(result), 159);
+        let result = solve_part1(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 159);
     }
 
     #[test]
     fn part1_example3() {
 		let input = String::from("R98,U47,R26,D63,R33,U87,L62,D20,R33,U53,R51\r\nU98,R91,D20,R16,D67,R40,U7,R15,U6,R7");
-        let result = solve_part1(&input);
-        assert_eq!(u64::from(result), 135);
+        let result = solve_part1(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 135);
     }
 
     #[test]
     fn part2_example1() {
         let input = String::from("R8,U5,L5,D3\r\nU7,R6,D4,L4");
-        let result = solve_part2(&input);
-        assert_eq!(u64::from(result), 30);
+        let result = solve_part2(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 30);
     }
 
     #[test]
     fn part2_example2() {
 		let input = String::from("R75,D30,R83,U83,L12,D49,R71,U7,L72\r\nU62,R66,U55,R34,D71,R55,D58,R83");
-        let result = solve_part2(&input);
-        assert_eq!(u64::from(result), 610);
+        let result = solve_part2(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 610);
     }
 
     #[test]
     fn part2_example3() {
 		let input = String::from("R98,U47,R26,D63,R33,U87,L62,D20,R33,U53,R51\r\nU98,R91,D20,R16,D67,R40,U7,R15,U6,R7");
-        let result = solve_part2(&input);
-        assert_eq!(u64::from(result), 410);
+        let result = solve_part2(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 410);
     }
 }
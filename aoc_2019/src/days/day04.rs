@@ -1,50 +1,51 @@
-use aoc_core::AoCResult;
+use aoc_core::{AoCError, AoCResult};
+use aoc_core::aoc_error::{column_of, parse_at};
 use itertools::Itertools;
-use std::str::FromStr;
 
-aoc_core::make_day!(Day04);
+aoc_core::make_day!(Day04, 2019, 4);
 
-pub fn solve_part1(input: &String) -> AoCResult {
-    let input_limits = input.split_once('-').unwrap();
-    let range_limits = (
-        u32::from_str(input_limits.0).unwrap(),
-        u32::from_str(input_limits.1).unwrap(),
-    );
+pub fn solve_part1(input: &str) -> Result<AoCResult, AoCError> {
+    let range_limits = get_range_limits(input)?;
 
     let valid_password_count = (range_limits.0..=range_limits.1)
         .map(|n| n.to_string())
-        .filter(|pwd| check_adjacent_equal_digits(&pwd) && check_no_decreasing_digits(&pwd))
+        .filter(|pwd| check_adjacent_equal_digits(pwd) && check_no_decreasing_digits(pwd))
         .count();
 
-    AoCResult::Num(u64::try_from(valid_password_count).unwrap())
+    Ok(AoCResult::Num(u64::try_from(valid_password_count).unwrap()))
 }
 
-pub fn solve_part2(input: &String) -> AoCResult {
-    let input_limits = input.split_once('-').unwrap();
-    let range_limits = (
-        u32::from_str(input_limits.0).unwrap(),
-        u32::from_str(input_limits.1).unwrap(),
-    );
+pub fn solve_part2(input: &str) -> Result<AoCResult, AoCError> {
+    let range_limits = get_range_limits(input)?;
 
     let valid_password_count = (range_limits.0..=range_limits.1)
         .map(|n| n.to_string())
         .filter(|pwd| {
-            check_only_two_adjacent_equal_digits(&pwd) && check_no_decreasing_digits(&pwd)
+            check_only_two_adjacent_equal_digits(pwd) && check_no_decreasing_digits(pwd)
         })
         .count();
 
-    AoCResult::Num(u64::try_from(valid_password_count).unwrap())
+    Ok(AoCResult::Num(u64::try_from(valid_password_count).unwrap()))
 }
 
-fn check_adjacent_equal_digits(password: &String) -> bool {
+fn get_range_limits(input: &str) -> Result<(u32, u32), AoCError> {
+    let input = input.trim_end();
+    let (lower, upper) = input
+        .split_once('-')
+        .ok_or_else(|| AoCError::parse_error(1, 1, "expected range 'lower-upper'"))?;
+
+    Ok((parse_at(lower, 1, 1)?, parse_at(upper, 1, column_of(input, upper))?))
+}
+
+fn check_adjacent_equal_digits(password: &str) -> bool {
     password.chars().tuple_windows().any(|(c1, c2)| c1 == c2)
 }
 
-fn check_no_decreasing_digits(password: &String) -> bool {
+fn check_no_decreasing_digits(password: &str) -> bool {
     password.chars().tuple_windows().all(|(c1, c2)| c1 <= c2)
 }
 
-fn check_only_two_adjacent_equal_digits(password: &String) -> bool {
+fn check_only_two_adjacent_equal_digits(password: &str) -> bool {
     // Count adjacent equal digits:
     let mut current_digit = ' ';
     let mut current_digit_count = 0;
@@ -61,7 +62,7 @@ fn check_only_two_adjacent_equal_digits(password: &String) -> bool {
         }
     }
 
-    return current_digit_count == 2;
+    current_digit_count == 2
 }
 
 #[cfg(test)]
@@ -71,42 +72,42 @@ mod tests {
     #[test]
     fn part1_example1() {
         let input = String::from("111111-111111");
-        let result = solve_part1(&input);
-        assert_eq!(u64::from(result), 1);
+        let result = solve_part1(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 1);
     }
 
     #[test]
     fn part1_example2() {
         let input = String::from("223450-223450");
-        let result = solve_part1(&input);
-        assert_eq!(u64::from(result), 0);
+        let result = solve_part1(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 0);
     }
 
     #[test]
     fn part1_example3() {
         let input = String::from("123789-123789");
-        let result = solve_part1(&input);
-        assert_eq!(u64::from(result), 0);
+        let result = solve_part1(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 0);
     }
 
     #[test]
     fn part2_example1() {
         let input = String::from("112233-112233");
-        let result = solve_part2(&input);
-        assert_eq!(u64::from(result), 1);
+        let result = solve_part2(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 1);
     }
 
     #[test]
     fn part2_example2() {
         let input = String::from("123444-123444");
-        let result = solve_part2(&input);
-        assert_eq!(u64::from(result), 0);
+        let result = solve_part2(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 0);
     }
 
     #[test]
     fn part2_example3() {
         let input = String::from("111122-111122");
-        let result = solve_part2(&input);
-        assert_eq!(u64::from(result), 1);
+        let result = solve_part2(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 1);
     }
 }
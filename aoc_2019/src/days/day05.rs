@@ -1,26 +1,30 @@
-use aoc_core::AoCResult;
+use aoc_core::{AoCError, AoCResult, PuzzleInput};
 use crate::shared::intcode::{IntcodeProgram, RunMode};
 
-aoc_core::make_day!(Day05);
+aoc_core::make_day!(Day05, 2019, 5, parsed: IntcodeProgram);
 
-pub fn solve_part1(input: &String) -> AoCResult {
-    let mut prgm = IntcodeProgram::new(input, None);
+pub fn parse(input: &PuzzleInput) -> Result<IntcodeProgram, AoCError> {
+    IntcodeProgram::new(input, None)
+}
+
+pub fn solve_part1(prgm: &IntcodeProgram) -> Result<AoCResult, AoCError> {
+    let mut prgm = prgm.clone();
 
     prgm.input.push_back(1);
     prgm.run(RunMode::Free);
 
-    let diagnostic_code = *prgm.output.last().unwrap();
-    AoCResult::Num(u64::try_from(diagnostic_code).unwrap())
+    let diagnostic_code = *prgm.output.last().ok_or(AoCError::NoSolution)?;
+    Ok(AoCResult::Int(diagnostic_code))
 }
 
-pub fn solve_part2(input: &String) -> AoCResult {
-    let mut prgm = IntcodeProgram::new(input, None);
+pub fn solve_part2(prgm: &IntcodeProgram) -> Result<AoCResult, AoCError> {
+    let mut prgm = prgm.clone();
 
     prgm.input.push_back(5);
     prgm.run(RunMode::Free);
 
-    let diagnostic_code = prgm.output[0];
-    AoCResult::Num(u64::try_from(diagnostic_code).unwrap())
+    let diagnostic_code = *prgm.output.first().ok_or(AoCError::NoSolution)?;
+    Ok(AoCResult::Int(diagnostic_code))
 }
 
 #[cfg(test)]
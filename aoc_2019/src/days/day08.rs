@@ -1,31 +1,44 @@
-use aoc_core::AoCResult;
+use aoc_core::{ocr, AoCError, AoCResult, Params};
 use std::collections::HashMap;
 use itertools::Itertools;
 
-aoc_core::make_day!(Day08);
+aoc_core::make_day!(Day08, 2019, 8, params: [width = "25", height = "6"]);
 
-pub fn solve_part1(input: &String) -> AoCResult {
-    AoCResult::Num(check_image_corruption(input, 25, 6))
+pub fn solve_part1(input: &str, params: &Params) -> Result<AoCResult, AoCError> {
+    check_image_corruption(input, params.get_positive("width")?, params.get_positive("height")?).map(AoCResult::Num)
 }
 
-fn get_layers(input: &String, image_width: usize, image_height: usize) -> Vec<String> {
-    input
+fn get_layers(input: &str, image_width: usize, image_height: usize) -> Result<Vec<String>, AoCError> {
+    let layer_size = image_width
+        .checked_mul(image_height)
+        .ok_or_else(|| AoCError::InvalidParameter(format!("image of {}x{} pixels is too large", image_width, image_height)))?;
+
+    // The image consists of full layers only:
+    let pixels = input.trim_end().chars().count();
+    if !pixels.is_multiple_of(layer_size) {
+        return Err(AoCError::parse_error(
+            1,
+            pixels - pixels % layer_size + 1,
+            format!("incomplete layer: expected {} pixels, found {}", layer_size, pixels % layer_size),
+        ));
+    }
+
+    Ok(input
         .trim_end()
         .chars()
-        .chunks(image_width * image_height)
+        .chunks(layer_size)
         .into_iter()
         .map(|layer| layer.collect::<String>())
-        .collect()
+        .collect())
 }
 
-fn check_image_corruption(input: &String, image_width: usize, image_height: usize) -> u64 {
+fn check_image_corruption(input: &str, image_width: usize, image_height: usize) -> Result<u64, AoCError> {
     // Split into layers:
-    let layers = get_layers(input, image_width, image_height);
+    let layers = get_layers(input, image_width, image_height)?;
 
     // Count frequencies of digits of each layer:
     let freqs: Vec<_> = layers
         .iter()
-        .cloned()
         .map(|layer| {
             layer.chars().fold(HashMap::new(), |mut map, c| {
                 map.entry(c).and_modify(|frq| *frq += 1).or_insert(1);
@@ -43,39 +56,78 @@ fn check_image_corruption(input: &String, image_width: usize, image_height: usiz
                 .unwrap_or(&0)
                 .cmp(layer2.get(&'0').unwrap_or(&0))
         })
-        .unwrap();
+        .ok_or(AoCError::NoSolution)?;
 
-    layer_max_0.get(&'1').unwrap_or(&0) * layer_max_0.get(&'2').unwrap_or(&0)
+    Ok(layer_max_0.get(&'1').unwrap_or(&0) * layer_max_0.get(&'2').unwrap_or(&0))
 }
 
-pub fn solve_part2(input: &String) -> AoCResult {
-    let layers = get_layers(input, 25, 6);
-
-    for y in 0..6 {
-        let mut line = String::new();
-        for x in 0..25 {
-            for layer in layers.iter() {
-                let c = layer.chars().nth(y * 25 + x).unwrap();
-                if c != '2' {
-                    line.push(if c == '1' { '#' } else { '.' });
-                    break;
-                }
-            }
-        }
-        println!("{}", line);
-    }
+pub fn solve_part2(input: &str, params: &Params) -> Result<AoCResult, AoCError> {
+    Ok(ocr::to_result(&decode_image(input, params.get_positive("width")?, params.get_positive("height")?)?))
+}
+
+// Stacks all layers, the first non-transparent pixel is visible (true = white)
+fn decode_image(input: &str, image_width: usize, image_height: usize) -> Result<Vec<Vec<bool>>, AoCError> {
+    let layers = get_layers(input, image_width, image_height)?;
 
-    AoCResult::PrintedToConsole
+    Ok((0..image_height)
+        .map(|y| {
+            (0..image_width)
+                .map(|x| {
+                    layers
+                        .iter()
+                        .map(|layer| layer.as_bytes()[y * image_width + x])
+                        .find(|&c| c != b'2')
+                        .is_some_and(|c| c == b'1')
+                })
+                .collect()
+        })
+        .collect())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{IoContext, PuzzleInput};
 
     #[test]
     fn part1_example1() {
-        let input = String::from("120256780012");
-        let result = check_image_corruption(&input, 3, 2);
-        assert_eq!(result, 2);
+        let input = PuzzleInput::from("120256780012");
+        let result = Day08 {}.run_part1(&input, &Params::from([("width", "3"), ("height", "2")]), &mut IoContext::silent()).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 2);
+    }
+
+    #[test]
+    fn part2_example1() {
+        let input = PuzzleInput::from("0222112222120000");
+        let result = Day08 {}.run_part2(&input, &Params::from([("width", "2"), ("height", "2")]), &mut IoContext::silent()).unwrap();
+        assert_eq!(result, AoCResult::Grid(vec![".#".to_string(), "#.".to_string()]));
+    }
+
+    #[test]
+    fn part1_empty_image() {
+        let input = PuzzleInput::from("");
+        let result = Day08 {}.run_part1(&input, &Params::from([("width", "3"), ("height", "2")]), &mut IoContext::silent());
+        assert!(matches!(result, Err(AoCError::NoSolution)));
+    }
+
+    #[test]
+    fn part2_incomplete_layer() {
+        let input = PuzzleInput::from("022211222");
+        let result = Day08 {}.run_part2(&input, &Params::from([("width", "2"), ("height", "2")]), &mut IoContext::silent());
+        assert!(matches!(result, Err(AoCError::ParseError { line: 1, column: 9, .. })));
+    }
+
+    #[test]
+    fn zero_width() {
+        let input = PuzzleInput::from("0222112222120000");
+        let result = Day08 {}.run_part1(&input, &Params::from([("width", "0")]), &mut IoContext::silent());
+        assert!(matches!(result, Err(AoCError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn unknown_param() {
+        let input = PuzzleInput::from("0222112222120000");
+        let result = Day08 {}.run_part1(&input, &Params::from([("depth", "2")]), &mut IoContext::silent());
+        assert!(matches!(result, Err(AoCError::InvalidParameter(_))));
     }
 }
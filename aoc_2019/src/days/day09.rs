@@ -1,29 +1,34 @@
-use aoc_core::AoCResult;
+use aoc_core::{AoCError, AoCResult, PuzzleInput};
 use crate::shared::intcode::{IntcodeProgram, RunMode};
 
-aoc_core::make_day!(Day09);
+aoc_core::make_day!(Day09, 2019, 9, parsed: IntcodeProgram);
 
-pub fn solve_part1(input: &String) -> AoCResult {
-    let mut prgm = IntcodeProgram::new(input, None);
+pub fn parse(input: &PuzzleInput) -> Result<IntcodeProgram, AoCError> {
+    IntcodeProgram::new(input, None)
+}
+
+pub fn solve_part1(prgm: &IntcodeProgram) -> Result<AoCResult, AoCError> {
+    let mut prgm = prgm.clone();
     prgm.input.push_back(1);
     prgm.run(RunMode::Free);
 
-    let boost_keycode = *prgm.output.last().unwrap();
-    AoCResult::Num(u64::try_from(boost_keycode).unwrap())
+    let boost_keycode = *prgm.output.last().ok_or(AoCError::NoSolution)?;
+    Ok(AoCResult::Int(boost_keycode))
 }
 
-pub fn solve_part2(input: &String) -> AoCResult {
-    let mut prgm = IntcodeProgram::new(input, None);
+pub fn solve_part2(prgm: &IntcodeProgram) -> Result<AoCResult, AoCError> {
+    let mut prgm = prgm.clone();
     prgm.input.push_back(2);
     prgm.run(RunMode::Free);
 
-    let coordinates = *prgm.output.last().unwrap();
-    AoCResult::Num(u64::try_from(coordinates).unwrap())
+    let coordinates = *prgm.output.last().ok_or(AoCError::NoSolution)?;
+    Ok(AoCResult::Int(coordinates))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::shared::intcode::InstructionResult;
 
     #[test]
     fn part1_example1() {
@@ -49,4 +54,16 @@ mod tests {
         prgm.run(RunMode::Free);
         assert_eq!(1125899906842624, prgm.output[0]);
     }
+
+    #[test]
+    fn invalid_address() {
+        let mut prgm = IntcodeProgram::from(vec![1, -1, 0, 0, 99]);
+        assert!(prgm.run(RunMode::Free) == InstructionResult::INVALID_ARGUMENT);
+    }
+
+    #[test]
+    fn parse_invalid_input() {
+        let input = PuzzleInput::from("");
+        assert!(matches!(parse(&input), Err(AoCError::ParseError { line: 1, column: 1, .. })));
+    }
 }
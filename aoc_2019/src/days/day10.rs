@@ -1,24 +1,25 @@
-use aoc_core::AoCResult;
+use aoc_core::{AoCError, AoCResult};
 use crate::shared::geometry::Point;
 use std::collections::HashMap;
 use std::f64::consts::PI;
 use itertools::Itertools;
 
-aoc_core::make_day!(Day10);
+aoc_core::make_day!(Day10, 2019, 10);
 
-pub fn solve_part1(input: &String) -> AoCResult {
-    let asteroids = get_asteroids(input);
+pub fn solve_part1(input: &str) -> Result<AoCResult, AoCError> {
+    let asteroids = get_asteroids(input)?;
     let all_connections = get_all_connections(&asteroids);
 
-    AoCResult::Num(*all_connections.iter().map(|(_, d)| d).max().unwrap())
+    let max_connections = all_connections.iter().map(|(_, d)| d).max().ok_or(AoCError::NoSolution)?;
+    Ok(AoCResult::Num(*max_connections))
 }
 
-pub fn solve_part2(input: &String) -> AoCResult {
-    let asteroids = get_asteroids(input);
+pub fn solve_part2(input: &str) -> Result<AoCResult, AoCError> {
+    let asteroids = get_asteroids(input)?;
     let all_connections = get_all_connections(&asteroids);
 
     // Point with maximum connections:
-    let p_max_conn = all_connections.iter().max_by(|(_, con1), (_, con2)| { con1.cmp(con2)}).unwrap().0;
+    let p_max_conn = all_connections.iter().max_by(|(_, con1), (_, con2)| { con1.cmp(con2)}).ok_or(AoCError::NoSolution)?.0;
 
     // Returns a list of "angles" and distances at which the other asteroids are
     let rotation_list = asteroids
@@ -74,34 +75,29 @@ pub fn solve_part2(input: &String) -> AoCResult {
     let mut order_of_destruction = Vec::<Point>::new();
     while order_of_destruction.len () < asteroids.len() - 1 {
         for (_, asteroids) in asteroid_by_angle.iter_mut().sorted_by_key(|(angle, _)| *angle) {
-            if asteroids.len() > 0 {
+            if !asteroids.is_empty() {
                 order_of_destruction.push(asteroids[0]);
                 asteroids.remove(0);
             }
         }
     }
 
-    let asteroid_200th = order_of_destruction[199];
-    AoCResult::Num(u64::try_from(asteroid_200th.x * 100 + asteroid_200th.y).unwrap())
+    let asteroid_200th = order_of_destruction.get(199).ok_or(AoCError::NoSolution)?;
+    Ok(AoCResult::Int(asteroid_200th.x * 100 + asteroid_200th.y))
 }
 
-fn get_asteroids(input: &String) -> Vec<Point> {
-    input
-        .trim_end()
-        .lines()
-        .enumerate()
-        // Rows:
-        .map(|(y, row)| {
-            row.chars()
-                .enumerate()
-                // Cols:
-                .map(move |(x, c)| (c, Point::from((x, y))))
-                .collect::<Vec<_>>()
-        })
-        .flatten()
-        .filter(|(c, _)| c == &'#')
-        .map(|(_, p)| p)
-        .collect()
+fn get_asteroids(input: &str) -> Result<Vec<Point>, AoCError> {
+    let mut asteroids = vec![];
+    for (y, row) in input.trim_end().lines().enumerate() {
+        for (x, c) in row.chars().enumerate() {
+            match c {
+                '#' => asteroids.push(Point::from((x, y))),
+                '.' => {}
+                _ => return Err(AoCError::parse_error(y + 1, x + 1, format!("unknown map tile '{}'", c))),
+            }
+        }
+    }
+    Ok(asteroids)
 }
 
 // Reduces the point like a fraction:
@@ -121,10 +117,10 @@ fn reduce(v: &Point) -> Point {
         }
     }
 
-    return *v;
+    *v
 }
 
-fn get_all_connections(asteroids: &Vec<Point>) -> Vec<(Point, u64)> {
+fn get_all_connections(asteroids: &[Point]) -> Vec<(Point, u64)> {
     let mut all_connections = Vec::<(Point, u64)>::new();
     for a1 in asteroids.iter() {
         let mut connections = 0u64;
@@ -149,7 +145,7 @@ fn get_all_connections(asteroids: &Vec<Point>) -> Vec<(Point, u64)> {
             }
         }
 
-        all_connections.push((a1.clone(), connections));
+        all_connections.push((*a1, connections));
     }
     all_connections
 }
@@ -161,42 +157,49 @@ mod tests {
     #[test]
     fn part1_example1() {
         let input = String::from(".#..#\r\n.....\r\n#####\r\n....#\r\n...##");
-        let result = solve_part1(&input);
-        assert_eq!(u64::from(result), 8);
+        let result = solve_part1(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 8);
     }
 
     #[test]
     fn part1_example2() {
         let input = String::from("......#.#.\r\n#..#.#....\r\n..#######.\r\n.#.#.###..\r\n.#..#.....\r\n..#....#.#\r\n#..#....#.\r\n.##.#..###\r\n##...#..#.\r\n.#....####");
-        let result = solve_part1(&input);
-        assert_eq!(u64::from(result), 33);
+        let result = solve_part1(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 33);
     }
 
     #[test]
     fn part1_example3() {
         let input = String::from("#.#...#.#.\r\n.###....#.\r\n.#....#...\r\n##.#.#.#.#\r\n....#.#.#.\r\n.##..###.#\r\n..#...##..\r\n..##....##\r\n......#...\r\n.####.###.");
-        let result = solve_part1(&input);
-        assert_eq!(u64::from(result), 35);
+        let result = solve_part1(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 35);
     }
 
     #[test]
     fn part1_example4() {
         let input = String::from(".#..#..###\r\n####.###.#\r\n....###.#.\r\n..###.##.#\r\n##.##.#.#.\r\n....###..#\r\n..#.#..#.#\r\n#..#.#.###\r\n.##...##.#\r\n.....#.#..");
-        let result = solve_part1(&input);
-        assert_eq!(u64::from(result), 41);
+        let result = solve_part1(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 41);
     }
 
     #[test]
     fn part1_example5() {
         let input = String::from(".#..##.###...#######\r\n##.############..##.\r\n.#.######.########.#\r\n.###.#######.####.#.\r\n#####.##.#.##.###.##\r\n..#####..#.#########\r\n####################\r\n#.####....###.#.#.##\r\n##.#################\r\n#####.##.###..####..\r\n..######..##.#######\r\n####.##.####...##..#\r\n.#####..#.######.###\r\n##...#.##########...\r\n#.##########.#######\r\n.####.#.###.###.#.##\r\n....##.##.###..#####\r\n.#.#.###########.###\r\n#.#.#.#####.####.###\r\n###.##.####.##.#..##");
-        let result = solve_part1(&input);
-        assert_eq!(u64::from(result), 210);
+        let result = solve_part1(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 210);
     }
 
     #[test]
     fn part2_example1() {
         let input = String::from(".#..##.###...#######\r\n##.############..##.\r\n.#.######.########.#\r\n.###.#######.####.#.\r\n#####.##.#.##.###.##\r\n..#####..#.#########\r\n####################\r\n#.####....###.#.#.##\r\n##.#################\r\n#####.##.###..####..\r\n..######..##.#######\r\n####.##.####...##..#\r\n.#####..#.######.###\r\n##...#.##########...\r\n#.##########.#######\r\n.####.#.###.###.#.##\r\n....##.##.###..#####\r\n.#.#.###########.###\r\n#.#.#.#####.####.###\r\n###.##.####.##.#..##");
-        let result = solve_part2(&input);
-        assert_eq!(u64::from(result), 802);
+        let result = solve_part2(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 802);
+    }
+
+    #[test]
+    fn part1_invalid_input() {
+        let input = String::from(".#..#\r\n..x..");
+        let result = solve_part1(&input);
+        assert!(matches!(result, Err(AoCError::ParseError { line: 2, column: 3, .. })));
     }
 }
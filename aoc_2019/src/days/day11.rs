@@ -1,28 +1,32 @@
-use aoc_core::AoCResult;
+use aoc_core::{ocr, AoCError, AoCResult, IoContext, PuzzleInput};
+use aoc_core::io_context::Frame;
 use crate::shared::geometry::Point;
 use crate::shared::intcode::{InstructionResult, IntcodeProgram, RunMode};
 use std::collections::HashMap;
 
-aoc_core::make_day!(Day11);
+aoc_core::make_day!(Day11, 2019, 11, parsed: IntcodeProgram, io);
 
-pub fn solve_part1(input: &String) -> AoCResult {
-    let mut prgm = IntcodeProgram::new(input, None);
+pub fn parse(input: &PuzzleInput) -> Result<IntcodeProgram, AoCError> {
+    IntcodeProgram::new(input, None)
+}
+
+pub fn solve_part1(prgm: &IntcodeProgram, io: &mut IoContext) -> Result<AoCResult, AoCError> {
+    let mut prgm = prgm.clone();
     let mut painting_robot = PaintingRobot::new();
 
-    painting_robot.run_paint_prgm(&mut prgm);
-    AoCResult::Num(painting_robot.get_painted_panels())
+    painting_robot.run_paint_prgm(&mut prgm, io)?;
+    Ok(AoCResult::Num(painting_robot.get_painted_panels()))
 }
 
-pub fn solve_part2(input: &String) -> AoCResult {
-    let mut prgm = IntcodeProgram::new(input, None);
+pub fn solve_part2(prgm: &IntcodeProgram, io: &mut IoContext) -> Result<AoCResult, AoCError> {
+    let mut prgm = prgm.clone();
     let mut painting_robot = PaintingRobot::new();
 
     // Start from white panel:
     painting_robot.paint(Color::White);
 
-    painting_robot.run_paint_prgm(&mut prgm);
-    painting_robot.print_to_console();
-    AoCResult::PrintedToConsole
+    painting_robot.run_paint_prgm(&mut prgm, io)?;
+    Ok(ocr::to_result(&painting_robot.get_image()))
 }
 
 #[derive(Copy, Clone, PartialEq)]
@@ -89,7 +93,7 @@ impl PaintingRobot {
         self.painting_area.len() as u64
     }
 
-    fn run_paint_prgm(&mut self, prgm: &mut IntcodeProgram) -> bool {
+    fn run_paint_prgm(&mut self, prgm: &mut IntcodeProgram, io: &mut IoContext) -> Result<(), AoCError> {
         let mut prgm_state = prgm.run(RunMode::Free);
         while prgm_state == InstructionResult::WAIT_FOR_INPUT {
             // Provide camera input to the program:
@@ -98,32 +102,63 @@ impl PaintingRobot {
             prgm_state = prgm.run_continue();
 
             if prgm_state == InstructionResult::WAIT_FOR_INPUT {
-                // Process output:
-                let color = match prgm.output[0] {
-                    0 => Color::Black,
-                    1 => Color::White,
-                    _ => unreachable!(),
+                // Process output (a color and a direction after each input):
+                let color = match prgm.output.first() {
+                    Some(0) => Color::Black,
+                    Some(1) => Color::White,
+                    _ => return Err(AoCError::NoSolution),
                 };
                 self.paint(color);
 
-                let turn_dir = match prgm.output[1] {
-                    0 => Direction::Left,
-                    1 => Direction::Right,
-                    _ => unreachable!(),
+                let turn_dir = match prgm.output.get(1) {
+                    Some(0) => Direction::Left,
+                    Some(1) => Direction::Right,
+                    _ => return Err(AoCError::NoSolution),
                 };
                 self.turn_and_move(turn_dir);
 
                 prgm.output.clear();
+
+                if io.wants_frames() {
+                    io.show_frame(&self.frame());
+                }
             } else if prgm_state != InstructionResult::HALT {
                 // Error during processing
-                return false;
+                return Err(AoCError::NoSolution);
             }
         }
 
-        return true;
+        Ok(())
     }
 
-    fn print_to_console(&self) {
+    // Painting area with the robot, looking in its direction
+    fn frame(&self) -> Frame {
+        let points = || self.painting_area.keys().chain([&self.position]);
+        let (x_min, x_max) = (points().map(|p| p.x).min().unwrap(), points().map(|p| p.x).max().unwrap());
+        let (y_min, y_max) = (points().map(|p| p.y).min().unwrap(), points().map(|p| p.y).max().unwrap());
+
+        let lines = (y_min..=y_max)
+            .map(|y| {
+                (x_min..=x_max)
+                    .map(|x| match Point::new(x, y) {
+                        p if p == self.position => match self.direction {
+                            Direction::Up => '^',
+                            Direction::Down => 'v',
+                            Direction::Left => '<',
+                            Direction::Right => '>',
+                        },
+                        p if self.painting_area.get(&p) == Some(&Color::White) => '#',
+                        _ => '.',
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Frame::new(lines, format!("Painted panels: {}", self.get_painted_panels()))
+    }
+
+    // Painted panels as rows of pixels (true = white)
+    fn get_image(&self) -> Vec<Vec<bool>> {
         // Dimensions of the painting area:
         let mut x_min = 0;
         let mut x_max = 0;
@@ -141,17 +176,25 @@ impl PaintingRobot {
 
         let width = x_max - x_min + 1;
         let height = y_max - y_min + 1;
-        let mut drawing = vec!['.'; (width * height) as usize];
+        let mut image = vec![vec![false; width as usize]; height as usize];
 
         for (p, color) in self.painting_area.iter() {
-            drawing[(p.y * width + p.x) as usize] = match color {
-                Color::Black => '.',
-                Color::White => '#',
-            }
+            image[(p.y - y_min) as usize][(p.x - x_min) as usize] = color == &Color::White;
         }
 
-        for line in drawing.chunks(width as usize) {
-            println!("{}", line.iter().collect::<String>());
-        }
+        image
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_invalid_color() {
+        // Paints with color 7 after the first camera input
+        let input = PuzzleInput::from("3,100,104,7,104,0,3,100,99");
+        let result = solve_part1(&parse(&input).unwrap(), &mut IoContext::silent());
+        assert!(matches!(result, Err(AoCError::NoSolution)));
     }
 }
@@ -1,19 +1,18 @@
-use aoc_core::AoCResult;
+use aoc_core::{AoCError, AoCResult, Params, PuzzleInput};
 use crate::shared::math;
-use regex::Regex;
 
-aoc_core::make_day!(Day12);
+aoc_core::make_day!(Day12, 2019, 12, params: [steps = "1000"]);
 
-pub fn solve_part1(input: &String) -> AoCResult {
-    let mut moons = parse_moons(input);
-    let total_energy = run_simulation(&mut moons, 1000, SimulationMode::All);
-    AoCResult::Num(total_energy)
+pub fn solve_part1(input: &PuzzleInput, params: &Params) -> Result<AoCResult, AoCError> {
+    let mut moons = parse_moons(input)?;
+    let total_energy = run_simulation(&mut moons, params.get_positive("steps")?, SimulationMode::All);
+    Ok(AoCResult::Num(total_energy))
 }
 
-pub fn solve_part2(input: &String) -> AoCResult {
-    let mut moons = parse_moons(input);
+pub fn solve_part2(input: &PuzzleInput, _params: &Params) -> Result<AoCResult, AoCError> {
+    let mut moons = parse_moons(input)?;
     let cycle = get_cycle_times(&mut moons);
-    AoCResult::Num(cycle)
+    Ok(AoCResult::Num(cycle))
 }
 
 #[derive(Clone, PartialEq)]
@@ -73,20 +72,13 @@ impl Moon {
     }
 }
 
-fn parse_moons(input: &String) -> Vec<Moon> {
-    let re = Regex::new("<x=([-0-9]+), y=([-0-9]+), z=([-0-9]+)>").unwrap();
+fn parse_moons(input: &PuzzleInput) -> Result<Vec<Moon>, AoCError> {
+    // Lines are formatted as <x=-1, y=0, z=2>
     input
         .lines()
-        .map(|l| {
-            let caps = re.captures(l.trim_end()).unwrap();
-            Moon {
-                x: caps[1].parse::<i64>().unwrap(),
-                y: caps[2].parse::<i64>().unwrap(),
-                z: caps[3].parse::<i64>().unwrap(),
-                vx: 0,
-                vy: 0,
-                vz: 0,
-            }
+        .map(|l| match l.ints::<i64>()?[..] {
+            [x, y, z] => Ok(Moon { x, y, z, vx: 0, vy: 0, vz: 0 }),
+            _ => Err(l.error("expected three coordinates")),
         })
         .collect()
 }
@@ -99,7 +91,7 @@ enum SimulationMode {
     All,
 }
 
-fn run_simulation(moons: &mut Vec<Moon>, cycles: usize, mode: SimulationMode) -> u64 {
+fn run_simulation(moons: &mut [Moon], cycles: usize, mode: SimulationMode) -> u64 {
     for _ in 0..cycles {
         // Update gravity:
         for i in 0..moons.len() {
@@ -107,15 +99,15 @@ fn run_simulation(moons: &mut Vec<Moon>, cycles: usize, mode: SimulationMode) ->
                 if i < j {
                     // Split at j: first slice contains i, second slice contains j at first position
                     let (moons_1, moons_2) = moons.split_at_mut(j);
-                    if mode == SimulationMode::All || mode == SimulationMode::OnlyX { moons_1[i].apply_gravity_from_x(&mut moons_2[0]); }
-                    if mode == SimulationMode::All || mode == SimulationMode::OnlyY { moons_1[i].apply_gravity_from_y(&mut moons_2[0]); }
-                    if mode == SimulationMode::All || mode == SimulationMode::OnlyZ { moons_1[i].apply_gravity_from_z(&mut moons_2[0]); }
+                    if mode == SimulationMode::All || mode == SimulationMode::OnlyX { moons_1[i].apply_gravity_from_x(&moons_2[0]); }
+                    if mode == SimulationMode::All || mode == SimulationMode::OnlyY { moons_1[i].apply_gravity_from_y(&moons_2[0]); }
+                    if mode == SimulationMode::All || mode == SimulationMode::OnlyZ { moons_1[i].apply_gravity_from_z(&moons_2[0]); }
                 } else if j < i {
                     // Split at i: first slice contains j, second slice contains i
                     let (moons_1, moons_2) = moons.split_at_mut(i);
-                    if mode == SimulationMode::All || mode == SimulationMode::OnlyX { moons_2[0].apply_gravity_from_x(&mut moons_1[j]); }
-                    if mode == SimulationMode::All || mode == SimulationMode::OnlyY { moons_2[0].apply_gravity_from_y(&mut moons_1[j]); }
-                    if mode == SimulationMode::All || mode == SimulationMode::OnlyZ { moons_2[0].apply_gravity_from_z(&mut moons_1[j]); }
+                    if mode == SimulationMode::All || mode == SimulationMode::OnlyX { moons_2[0].apply_gravity_from_x(&moons_1[j]); }
+                    if mode == SimulationMode::All || mode == SimulationMode::OnlyY { moons_2[0].apply_gravity_from_y(&moons_1[j]); }
+                    if mode == SimulationMode::All || mode == SimulationMode::OnlyZ { moons_2[0].apply_gravity_from_z(&moons_1[j]); }
                 }
             }
         }
@@ -165,28 +157,26 @@ fn get_cycle_times(moons: &mut Vec<Moon>) -> u64 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::IoContext;
 
     #[test]
     fn part1_example1() {
-        let input = String::from("<x=-1, y=0, z=2>\r\n<x=2, y=-10, z=-7>\r\n<x=4, y=-8, z=8>\r\n<x=3, y=5, z=-1>");
-        let mut moons = parse_moons(&input);
-        let total_energy = run_simulation(&mut moons, 10, SimulationMode::All);
-        assert_eq!(total_energy, 179);
+        let input = PuzzleInput::from("<x=-1, y=0, z=2>\r\n<x=2, y=-10, z=-7>\r\n<x=4, y=-8, z=8>\r\n<x=3, y=5, z=-1>");
+        let result = Day12 {}.run_part1(&input, &Params::from([("steps", "10")]), &mut IoContext::silent()).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 179);
     }
 
     #[test]
     fn part1_example2() {
-        let input = String::from("<x=-8, y=-10, z=0>\r\n<x=5, y=5, z=10>\r\n<x=2, y=-7, z=3>\r\n<x=9, y=-8, z=-3>");
-        let mut moons = parse_moons(&input);
-        let total_energy = run_simulation(&mut moons, 100, SimulationMode::All);
-        assert_eq!(total_energy, 1940);
+        let input = PuzzleInput::from("<x=-8, y=-10, z=0>\r\n<x=5, y=5, z=10>\r\n<x=2, y=-7, z=3>\r\n<x=9, y=-8, z=-3>");
+        let result = Day12 {}.run_part1(&input, &Params::from([("steps", "100")]), &mut IoContext::silent()).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 1940);
     }
 
     #[test]
     fn part2_example1() {
-        let input = String::from("<x=-1, y=0, z=2>\r\n<x=2, y=-10, z=-7>\r\n<x=4, y=-8, z=8>\r\n<x=3, y=5, z=-1>");
-        let mut moons = parse_moons(&input);
-        let cycle = get_cycle_times(&mut moons);
-        assert_eq!(cycle, 2772);
+        let input = PuzzleInput::from("<x=-1, y=0, z=2>\r\n<x=2, y=-10, z=-7>\r\n<x=4, y=-8, z=8>\r\n<x=3, y=5, z=-1>");
+        let result = Day12 {}.run_part2(&input, &Params::new(), &mut IoContext::silent()).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 2772);
     }
 }
@@ -1,33 +1,29 @@
-use aoc_core::AoCResult;
+use aoc_core::{AoCError, AoCResult, IoContext, PuzzleInput};
+use aoc_core::io_context::Frame;
 use crate::shared::intcode::{InstructionResult, IntcodeProgram, RunMode};
-use std::{thread, time};
 
-aoc_core::make_day!(Day13);
+aoc_core::make_day!(Day13, 2019, 13, parsed: IntcodeProgram, io);
 
-pub fn solve_part1(input: &String) -> AoCResult {
-    let mut prgm = IntcodeProgram::new(input, None);
+pub fn parse(input: &PuzzleInput) -> Result<IntcodeProgram, AoCError> {
+    IntcodeProgram::new(input, None)
+}
+
+pub fn solve_part1(prgm: &IntcodeProgram, _io: &mut IoContext) -> Result<AoCResult, AoCError> {
+    let mut prgm = prgm.clone();
     prgm.run(RunMode::Free);
 
-    let tiles: Vec<_> = prgm
-        .output
-        .chunks(3)
-        .map(|chunk| Tile {
-            x: chunk[0],
-            y: chunk[1],
-            tile_id: TileId::from(chunk[2] as u64),
-        })
-        .collect();
+    let tiles = get_tiles(&prgm.output);
 
     let block_tiles = tiles
         .iter()
         .filter(|tile| tile.tile_id == TileId::Block)
         .count();
 
-    AoCResult::Num(block_tiles as u64)
+    Ok(AoCResult::Num(block_tiles as u64))
 }
 
-pub fn solve_part2(input: &String) -> AoCResult {
-    let mut prgm = IntcodeProgram::new(input, None);
+pub fn solve_part2(prgm: &IntcodeProgram, io: &mut IoContext) -> Result<AoCResult, AoCError> {
+    let mut prgm = prgm.clone();
 
     // Set memory location 0:
     prgm.write(0, 2);
@@ -39,25 +35,18 @@ pub fn solve_part2(input: &String) -> AoCResult {
     let mut paddle_x = 0i64;
 
     while prgm_state == InstructionResult::WAIT_FOR_INPUT {
-        let tiles: Vec<_> = prgm
-            .output
-            .chunks(3)
-            .map(|chunk| Tile {
-                x: chunk[0],
-                y: chunk[1],
-                tile_id: if chunk[0] >= 0 {
-                    TileId::from(chunk[2] as u64)
-                } else {
-                    TileId::Score(chunk[2] as u64)
-                },
-            })
-            .collect();
+        let tiles = get_tiles(&prgm.output);
         prgm.output.clear();
 
+        // A game that waits for input without changing the screen never ends:
+        if tiles.is_empty() {
+            return Err(AoCError::NoSolution);
+        }
+
         if let Some(old_screen) = screen.as_mut() {
             old_screen.update(&tiles);
         } else {
-            let new_screen = Screen::new(&tiles);
+            let new_screen = Screen::new(&tiles)?;
             screen = Some(new_screen);
         }
 
@@ -79,16 +68,27 @@ pub fn solve_part2(input: &String) -> AoCResult {
             prgm.input.push_back(0);
         }
 
-        // screen.as_mut().unwrap().print_to_console();
-        // thread::sleep(time::Duration::from_millis(50));
+        // Show the game while it is played:
+        if io.wants_frames() && let Some(screen) = screen.as_ref() {
+            io.show_frame(&screen.frame());
+        }
 
         prgm_state = prgm.run_continue();
     }
 
     // Last update:
-    let tiles: Vec<_> = prgm
-        .output
-        .chunks(3)
+    let mut screen = screen.ok_or(AoCError::NoSolution)?;
+    screen.update(&get_tiles(&prgm.output));
+    if io.wants_frames() {
+        io.show_frame(&screen.frame());
+    }
+    Ok(AoCResult::Num(screen.score))
+}
+
+// Every three output values describe one tile (x = -1 is the score display)
+fn get_tiles(output: &[i64]) -> Vec<Tile> {
+    output
+        .chunks_exact(3)
         .map(|chunk| Tile {
             x: chunk[0],
             y: chunk[1],
@@ -98,10 +98,7 @@ pub fn solve_part2(input: &String) -> AoCResult {
                 TileId::Score(chunk[2] as u64)
             },
         })
-        .collect();
-
-    screen.as_mut().unwrap().update(&tiles);
-    AoCResult::Num(screen.unwrap().score)
+        .collect()
 }
 
 #[derive(PartialEq)]
@@ -138,7 +135,7 @@ struct Screen {
 }
 
 impl Screen {
-    fn new(tiles: &Vec<Tile>) -> Self {
+    fn new(tiles: &[Tile]) -> Result<Self, AoCError> {
         // Dimensions of the painting area:
         let mut width = 0;
         let mut height = 0;
@@ -151,7 +148,7 @@ impl Screen {
                 } else {
                     0
                 };
-            } else {
+            } else if t.y >= 0 {
                 if t.x >= width {
                     width = t.x + 1;
                 }
@@ -161,31 +158,25 @@ impl Screen {
             }
         }
 
-        let mut drawing = vec!['.'; (width * height) as usize];
-
-        for t in tiles.iter().filter(|t| t.x >= 0) {
-            drawing[(t.y * width + t.x) as usize] = match t.tile_id {
-                TileId::Wall => '#',
-                TileId::Block => 'X',
-                TileId::Ball => 'o',
-                TileId::Paddle => '=',
-                _ => ' ',
-            }
-        }
-
-        Screen {
-            width: width,
-            score: score,
-            drawing_area: drawing,
-        }
+        let area = width.checked_mul(height)
+            .and_then(|area| usize::try_from(area).ok())
+            .ok_or(AoCError::NoSolution)?;
+        let mut screen = Screen {
+            width,
+            score,
+            drawing_area: vec!['.'; area],
+        };
+        screen.update(tiles);
+        Ok(screen)
     }
 
-    fn update(&mut self, tiles: &Vec<Tile>) {
+    fn update(&mut self, tiles: &[Tile]) {
         for t in tiles.iter() {
             if let TileId::Score(s) = t.tile_id {
                 self.score = s;
-            } else {
-                self.drawing_area[(t.y * self.width + t.x) as usize] = match t.tile_id {
+            } else if let Some(cell) = self.cell(t.x, t.y) {
+                // (tiles outside of the screen are ignored)
+                *cell = match t.tile_id {
                     TileId::Wall => '#',
                     TileId::Block => 'X',
                     TileId::Ball => 'o',
@@ -196,10 +187,35 @@ impl Screen {
         }
     }
 
-    fn print_to_console(&self) {
-        println!("Score: {}", self.score);
-        for line in self.drawing_area.chunks(self.width as usize) {
-            println!("{}", line.iter().collect::<String>());
+    fn cell(&mut self, x: i64, y: i64) -> Option<&mut char> {
+        if x < 0 || y < 0 || x >= self.width {
+            return None;
         }
+        self.drawing_area.get_mut((y * self.width + x) as usize)
+    }
+
+    fn frame(&self) -> Frame {
+        Frame::from_chars(&self.drawing_area, self.width as usize, format!("Score: {}", self.score))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part2_no_screen() {
+        // Program that keeps reading joystick input without drawing anything
+        let input = PuzzleInput::from("1,0,0,0,3,0,1105,1,4");
+        let result = solve_part2(&parse(&input).unwrap(), &mut IoContext::silent());
+        assert!(matches!(result, Err(AoCError::NoSolution)));
+    }
+
+    #[test]
+    fn part2_screen_too_large() {
+        // Program that draws a wall far away and then waits for input
+        let input = PuzzleInput::from("104,4000000000,104,4000000000,104,1,3,0,99");
+        let result = solve_part2(&parse(&input).unwrap(), &mut IoContext::silent());
+        assert!(matches!(result, Err(AoCError::NoSolution)));
     }
 }
@@ -1,12 +1,11 @@
-use aoc_core::AoCResult;
+use aoc_core::{AoCError, AoCResult, PuzzleInput};
+use aoc_core::puzzle_input::Line;
 use itertools::Itertools;
 use std::collections::{HashMap, VecDeque};
 
-aoc_core::make_day!(Day14);
-
-pub fn solve_part1(input: &String) -> AoCResult {
-    let reactions = get_reactions(input);
+aoc_core::make_day!(Day14, 2019, 14, parsed: Reactions);
 
+pub fn solve_part1(reactions: &Reactions) -> Result<AoCResult, AoCError> {
     // Process all chemicals needed to produce 1 fuel:
     let mut chemicals_to_produce: VecDeque<Chemical> = VecDeque::new();
     chemicals_to_produce.push_back(Chemical {
@@ -14,22 +13,26 @@ pub fn solve_part1(input: &String) -> AoCResult {
         quantity: 1,
     });
 
-    let ore_amount = produce_chemicals_from_ore(&reactions, &mut chemicals_to_produce);
-    AoCResult::Num(ore_amount)
+    let ore_amount = produce_chemicals_from_ore(reactions, &mut chemicals_to_produce)?;
+    Ok(AoCResult::Num(ore_amount))
 }
 
-pub fn solve_part2(input: &String) -> AoCResult {
-    let reactions = get_reactions(input);
-
+pub fn solve_part2(reactions: &Reactions) -> Result<AoCResult, AoCError> {
     // From part 1: produce 1 fuel to get unused chemicals during the process
     let mut chemicals_to_produce: VecDeque<Chemical> = VecDeque::new();
     chemicals_to_produce.push_back(Chemical {
         name: "FUEL".to_owned(),
         quantity: 1,
     });
-    let ore_for_1_fuel = produce_chemicals_from_ore(&reactions, &mut chemicals_to_produce);
+    let ore_for_1_fuel = produce_chemicals_from_ore(reactions, &mut chemicals_to_produce)?;
 
     let available_ore = 1000000000000u64;
+    if ore_for_1_fuel == 0 {
+        // Unlimited fuel
+        return Err(AoCError::NoSolution);
+    } else if ore_for_1_fuel > available_ore {
+        return Ok(AoCResult::Num(0));
+    }
 
     // Get lower bound for amount of fuel that can be produced from this:
     let mut fuel_lower_bound = available_ore / ore_for_1_fuel;
@@ -41,7 +44,7 @@ pub fn solve_part2(input: &String) -> AoCResult {
             name: "FUEL".to_owned(),
             quantity: fuel_upper_bound,
         });
-        let ore_amount = produce_chemicals_from_ore(&reactions, &mut chemicals_to_produce);
+        let ore_amount = produce_chemicals_from_ore(reactions, &mut chemicals_to_produce)?;
         if ore_amount > available_ore {
             break;
         } else {
@@ -58,7 +61,7 @@ pub fn solve_part2(input: &String) -> AoCResult {
             name: "FUEL".to_owned(),
             quantity: fuel_amount,
         });
-        let ore_amount = produce_chemicals_from_ore(&reactions, &mut chemicals_to_produce);
+        let ore_amount = produce_chemicals_from_ore(reactions, &mut chemicals_to_produce)?;
 
         if ore_amount > available_ore {
             // Too much, lower upper bound:
@@ -77,32 +80,37 @@ pub fn solve_part2(input: &String) -> AoCResult {
         }
     }
 
-    AoCResult::Num(fuel_lower_bound)
+    Ok(AoCResult::Num(fuel_lower_bound))
 }
 
-fn get_reactions(input: &String) -> HashMap<String, Reaction> {
+// Reactions by the name of the chemical they produce
+pub type Reactions = HashMap<String, Reaction>;
+
+pub fn parse(input: &PuzzleInput) -> Result<Reactions, AoCError> {
     input
-        .trim_end()
         .lines()
         .map(|l| {
-            let in_out = l.split_once("=>").unwrap();
+            let in_out = l.split_once_checked("=>")?;
             
             let input = in_out.0.split(",")
-                .map(|i| Chemical::from(i))
-                .collect_vec();
+                .map(Chemical::try_from)
+                .try_collect()?;
 
-            let output = Chemical::from(in_out.1);
+            let output = Chemical::try_from(in_out.1)?;
+            if output.quantity == 0 {
+                return Err(in_out.1.trim().error("reaction produces nothing"));
+            }
 
-            (output.name.clone(), Reaction { input, output: output })
+            Ok((output.name.clone(), Reaction { input, output }))
 
         })
         .collect()
 }
 
 fn produce_chemicals_from_ore(
-    reactions: &HashMap<String, Reaction>,
+    reactions: &Reactions,
     chemicals_to_produce: &mut VecDeque<Chemical>,
-) -> u64 {
+) -> Result<u64, AoCError> {
     // Get amount of ORE needed for producing the given chemicals:
     let mut ore_amount = 0u64;
 
@@ -128,10 +136,10 @@ fn produce_chemicals_from_ore(
                 ore_amount += next_chem.quantity;
             } else {
                 // Find reaction to procude this chem:
-                let reaction = reactions.get(&next_chem.name).unwrap();
+                let reaction = reactions.get(&next_chem.name).ok_or(AoCError::NoSolution)?;
                 let scale = if reaction.output.quantity >= next_chem.quantity {
                     1
-                } else if next_chem.quantity % reaction.output.quantity != 0 {
+                } else if !next_chem.quantity.is_multiple_of(reaction.output.quantity) {
                     next_chem.quantity / reaction.output.quantity + 1
                 } else {
                     next_chem.quantity / reaction.output.quantity
@@ -159,11 +167,11 @@ fn produce_chemicals_from_ore(
         }
     }
 
-    ore_amount
+    Ok(ore_amount)
 }
 
 #[derive(Clone)]
-struct Chemical {
+pub struct Chemical {
     name: String,
     quantity: u64,
 }
@@ -178,17 +186,19 @@ impl Chemical {
     }
 }
 
-impl From<&str> for Chemical {
-    fn from(value: &str) -> Self {
-        let quan_and_ingr = value.trim().split_once(' ').unwrap();
-        Chemical {
-            name: quan_and_ingr.1.to_owned(),
-            quantity: quan_and_ingr.0.parse().unwrap(),
-        }
+impl TryFrom<Line<'_>> for Chemical {
+    type Error = AoCError;
+
+    fn try_from(value: Line<'_>) -> Result<Self, Self::Error> {
+        let quan_and_ingr = value.trim().split_once_checked(" ")?;
+        Ok(Chemical {
+            name: quan_and_ingr.1.trim().to_string(),
+            quantity: quan_and_ingr.0.parse()?,
+        })
     }
 }
 
-struct Reaction {
+pub struct Reaction {
     input: Vec<Chemical>,
     output: Chemical,
 }
@@ -199,15 +209,29 @@ mod tests {
 
     #[test]
     fn part1_example1() {
-        let input = String::from("10 ORE => 10 A\r\n1 ORE => 1 B\r\n7 A, 1 B => 1 C\r\n7 A, 1 C => 1 D\r\n7 A, 1 D => 1 E\r\n7 A, 1 E => 1 FUEL");
-        let result = solve_part1(&input);
-        assert_eq!(u64::from(result), 31);
+        let input = PuzzleInput::from("10 ORE => 10 A\r\n1 ORE => 1 B\r\n7 A, 1 B => 1 C\r\n7 A, 1 C => 1 D\r\n7 A, 1 D => 1 E\r\n7 A, 1 E => 1 FUEL");
+        let result = solve_part1(&parse(&input).unwrap()).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 31);
     }
 
     #[test]
     fn part1_example2() {
-        let input = String::from("9 ORE => 2 A\r\n8 ORE => 3 B\r\n7 ORE => 5 C\r\n3 A, 4 B => 1 AB\r\n5 B, 7 C => 1 BC\r\n4 C, 1 A => 1 CA\r\n2 AB, 3 BC, 4 CA => 1 FUEL");
-        let result = solve_part1(&input);
-        assert_eq!(u64::from(result), 165);
+        let input = PuzzleInput::from("9 ORE => 2 A\r\n8 ORE => 3 B\r\n7 ORE => 5 C\r\n3 A, 4 B => 1 AB\r\n5 B, 7 C => 1 BC\r\n4 C, 1 A => 1 CA\r\n2 AB, 3 BC, 4 CA => 1 FUEL");
+        let result = solve_part1(&parse(&input).unwrap()).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 165);
+    }
+
+    #[test]
+    fn part1_invalid_input() {
+        let input = PuzzleInput::from("10 ORE => 10 A\r\n7 A, 1B => 1 FUEL");
+        let result = parse(&input);
+        assert!(matches!(result, Err(AoCError::ParseError { line: 2, column: 6, .. })));
+    }
+
+    #[test]
+    fn part1_missing_reaction() {
+        let input = PuzzleInput::from("10 ORE => 10 A\r\n7 A, 1 B => 1 FUEL");
+        let result = solve_part1(&parse(&input).unwrap());
+        assert!(matches!(result, Err(AoCError::NoSolution)));
     }
 }
@@ -1,12 +1,21 @@
-use aoc_core::AoCResult;
+use aoc_core::{AoCError, AoCResult, IoContext, PuzzleInput};
+use aoc_core::io_context::Frame;
+use aoc_core::log::Level;
 use crate::shared::geometry::Point;
 use crate::shared::intcode::{IntcodeProgram, RunMode};
 use std::collections::{HashMap, HashSet, VecDeque};
 
-aoc_core::make_day!(Day15);
+aoc_core::make_day!(Day15, 2019, 15, parsed: IntcodeProgram, io);
 
-pub fn solve_part1(input: &String) -> AoCResult {
-    let mut prgm = IntcodeProgram::new(input, None);
+// The area has to be enclosed by walls within this distance from the start
+const MAX_DISTANCE: i64 = 1000;
+
+pub fn parse(input: &PuzzleInput) -> Result<IntcodeProgram, AoCError> {
+    IntcodeProgram::new(input, None)
+}
+
+pub fn solve_part1(prgm: &IntcodeProgram, io: &mut IoContext) -> Result<AoCResult, AoCError> {
+    let mut prgm = prgm.clone();
 
     // Movement of the robot, stack-based depth-first search:
     let mut movement_stack = vec![Point::new(0, 0)];
@@ -15,18 +24,18 @@ pub fn solve_part1(input: &String) -> AoCResult {
     let mut visited_locations = HashMap::<Point, StatusCode>::new();
 
     let mut distance_moved = 0u64;
-    let mut distance_to_oxygen = 0u64;
+    let mut distance_to_oxygen = None;
 
     // Start program:
     prgm.run(RunMode::Free);
 
     while !movement_stack.is_empty() {
         // Current top of the stack:
-        let current_pos = movement_stack.last().unwrap().clone();
+        let current_pos = *movement_stack.last().unwrap();
 
         // Keep track of explored locations:
         visited_locations
-            .entry(current_pos.clone())
+            .entry(current_pos)
             .or_insert(StatusCode::Empty);
 
         // Try moving in a new direction:
@@ -58,7 +67,7 @@ pub fn solve_part1(input: &String) -> AoCResult {
                         if movement_stack.is_empty() {
                             break;
                         } else {
-                            next_pos = movement_stack.last().unwrap().clone();
+                            next_pos = *movement_stack.last().unwrap();
                             moved_backwards = true;
                         }
                     }
@@ -75,16 +84,22 @@ pub fn solve_part1(input: &String) -> AoCResult {
             _ => panic!("Skipped a step!")
         }
 
+        if next_pos.x.abs() > MAX_DISTANCE || next_pos.y.abs() > MAX_DISTANCE {
+            return Err(AoCError::NoSolution);
+        }
+
         prgm.run_continue();
 
-        // Check output:
-        if !prgm.output.is_empty() {
+        // Check output (the droid has to report the result of every move):
+        if prgm.output.is_empty() {
+            return Err(AoCError::NoSolution);
+        } else {
             match prgm.output[0] {
                 1 => {
                     // Moved
                     if !moved_backwards {
                         distance_moved += 1;
-                        movement_stack.push(next_pos.clone());
+                        movement_stack.push(next_pos);
                     } else {
                         distance_moved -= 1;
                     }
@@ -93,31 +108,37 @@ pub fn solve_part1(input: &String) -> AoCResult {
                     // Oxygen system
                     if !moved_backwards {
                         distance_moved += 1;
-                        movement_stack.push(next_pos.clone());
+                        movement_stack.push(next_pos);
                     } else {
                         distance_moved -= 1;
                     }
 
-                    distance_to_oxygen = distance_moved;
-                    visited_locations.insert(next_pos.clone(), StatusCode::OxygenSystem);
+                    distance_to_oxygen = Some(distance_moved);
+                    visited_locations.insert(next_pos, StatusCode::OxygenSystem);
                 }
                 _ => {
                     // Wall / other
-                    visited_locations.insert(next_pos.clone(), StatusCode::Wall);
+                    visited_locations.insert(next_pos, StatusCode::Wall);
                 }
             }
 
             prgm.output.clear();
         }
+
+        // Show the droid exploring the area (it is always at the top of the stack):
+        if io.wants_frames() && let Some(droid) = movement_stack.last() {
+            let map = draw_map(&visited_locations, &[(*droid, 'D')]);
+            io.show_frame(&Frame::new(map, format!("Distance: {}", distance_moved)));
+        }
     }
 
-    print_to_console(&visited_locations);
+    log_map(&visited_locations);
 
-    AoCResult::Num(distance_to_oxygen)
+    distance_to_oxygen.map(AoCResult::Num).ok_or(AoCError::NoSolution)
 }
 
-pub fn solve_part2(input: &String) -> AoCResult {
-    let mut prgm = IntcodeProgram::new(input, None);
+pub fn solve_part2(prgm: &IntcodeProgram, io: &mut IoContext) -> Result<AoCResult, AoCError> {
+    let mut prgm = prgm.clone();
 
     // Movement of the robot, stack-based depth-first search:
     let mut movement_stack = vec![Point::new(0, 0)];
@@ -130,11 +151,11 @@ pub fn solve_part2(input: &String) -> AoCResult {
 
     while !movement_stack.is_empty() {
         // Current top of the stack:
-        let current_pos = movement_stack.last().unwrap().clone();
+        let current_pos = *movement_stack.last().unwrap();
 
         // Keep track of explored locations:
         visited_locations
-            .entry(current_pos.clone())
+            .entry(current_pos)
             .or_insert(StatusCode::Empty);
 
         // Try moving in a new direction:
@@ -166,7 +187,7 @@ pub fn solve_part2(input: &String) -> AoCResult {
                         if movement_stack.is_empty() {
                             break;
                         } else {
-                            next_pos = movement_stack.last().unwrap().clone();
+                            next_pos = *movement_stack.last().unwrap();
                             moved_backwards = true;
                         }
                     }
@@ -183,28 +204,34 @@ pub fn solve_part2(input: &String) -> AoCResult {
             _ => panic!("Skipped a step!")
         }
 
+        if next_pos.x.abs() > MAX_DISTANCE || next_pos.y.abs() > MAX_DISTANCE {
+            return Err(AoCError::NoSolution);
+        }
+
         prgm.run_continue();
 
-        // Check output:
-        if !prgm.output.is_empty() {
+        // Check output (the droid has to report the result of every move):
+        if prgm.output.is_empty() {
+            return Err(AoCError::NoSolution);
+        } else {
             match prgm.output[0] {
                 1 => {
                     // Moved
                     if !moved_backwards {
-                        movement_stack.push(next_pos.clone());
+                        movement_stack.push(next_pos);
                     }
                 }
                 2 => {
                     // Oxygen system
                     if !moved_backwards {
-                        movement_stack.push(next_pos.clone());
+                        movement_stack.push(next_pos);
                     }
 
-                    visited_locations.insert(next_pos.clone(), StatusCode::OxygenSystem);
+                    visited_locations.insert(next_pos, StatusCode::OxygenSystem);
                 }
                 _ => {
                     // Wall / other
-                    visited_locations.insert(next_pos.clone(), StatusCode::Wall);
+                    visited_locations.insert(next_pos, StatusCode::Wall);
                 }
             }
 
@@ -217,18 +244,24 @@ pub fn solve_part2(input: &String) -> AoCResult {
     let oxygen_start = visited_locations
         .iter()
         .find(|x| x.1 == &StatusCode::OxygenSystem)
-        .unwrap()
+        .ok_or(AoCError::NoSolution)?
         .0;
-    oxygen.insert(oxygen_start.clone());
+    oxygen.insert(*oxygen_start);
 
     // BFS for oxygen spread with minute the position was reached:
     let mut oxigen_queue = VecDeque::<(Point, u64)>::new();
-    oxigen_queue.push_back((oxygen_start.clone(), 0));
+    oxigen_queue.push_back((*oxygen_start, 0));
 
     let mut max_time = 0;
     while !oxigen_queue.is_empty() {
         let (current_pos, time) = oxigen_queue.pop_front().unwrap();
 
+        // Show the oxygen spreading, once per minute:
+        if io.wants_frames() && (time > max_time || oxygen.len() == 1) {
+            let filled: Vec<_> = oxygen.iter().map(|p| (*p, 'O')).collect();
+            io.show_frame(&Frame::new(draw_map(&visited_locations, &filled), format!("Minute: {}", time)));
+        }
+
         max_time = std::cmp::max(max_time, time);
 
         // Find all neighbors from visited set:
@@ -246,13 +279,13 @@ pub fn solve_part2(input: &String) -> AoCResult {
                     .find(|x| x.1 == &StatusCode::Empty && x.0 == &neighbor)
                     .is_some()
             {
-                oxygen.insert(neighbor.clone());
+                oxygen.insert(neighbor);
                 oxigen_queue.push_back((neighbor, time + 1));
             }
         }
     }
 
-    AoCResult::Num(max_time)
+    Ok(AoCResult::Num(max_time))
 }
 
 #[derive(PartialEq)]
@@ -262,7 +295,18 @@ enum StatusCode {
     OxygenSystem,
 }
 
-fn print_to_console(points: &HashMap<Point, StatusCode>) {
+fn log_map(points: &HashMap<Point, StatusCode>) {
+    if !aoc_core::log::enabled(Level::Trace) {
+        return;
+    }
+
+    for line in draw_map(points, &[]) {
+        aoc_core::trace!("{}", line);
+    }
+}
+
+// Lines of the explored area, with some points drawn on top of it (e.g. the droid)
+fn draw_map(points: &HashMap<Point, StatusCode>, overlay: &[(Point, char)]) -> Vec<String> {
     // Dimensions of the painting area:
     let mut x_min = 0;
     let mut x_max = 0;
@@ -291,7 +335,30 @@ fn print_to_console(points: &HashMap<Point, StatusCode>) {
         }
     }
 
-    for line in drawing.chunks(width as usize) {
-        println!("{}", line.iter().collect::<String>());
+    for (p, c) in overlay {
+        drawing[((p.y - y_min) * width + (p.x - x_min)) as usize] = *c;
+    }
+
+    drawing.chunks(width as usize).map(|line| line.iter().collect()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_silent_droid() {
+        // Droid that takes commands without ever reporting back
+        let input = PuzzleInput::from("3,100,1105,1,0");
+        let result = solve_part1(&parse(&input).unwrap(), &mut IoContext::silent());
+        assert!(matches!(result, Err(AoCError::NoSolution)));
+    }
+
+    #[test]
+    fn part2_open_area() {
+        // Droid that can always move, so the area has no walls
+        let input = PuzzleInput::from("3,100,104,1,1105,1,0");
+        let result = solve_part2(&parse(&input).unwrap(), &mut IoContext::silent());
+        assert!(matches!(result, Err(AoCError::NoSolution)));
     }
 }
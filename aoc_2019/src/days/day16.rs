@@ -1,14 +1,9 @@
-use aoc_core::AoCResult;
-use itertools::Itertools;
+use aoc_core::{AoCError, AoCResult};
 
-aoc_core::make_day!(Day16);
+aoc_core::make_day!(Day16, 2019, 16);
 
-pub fn solve_part1(input: &String) -> AoCResult {
-    let mut data = input
-        .trim()
-        .chars()
-        .map(|c| c.to_digit(10).unwrap() as u8)
-        .collect_vec();
+pub fn solve_part1(input: &str) -> Result<AoCResult, AoCError> {
+    let mut data = parse_digits(input)?;
 
     let base_pattern: Vec<i32> = vec![0, 1, 0, -1];
 
@@ -54,15 +49,11 @@ pub fn solve_part1(input: &String) -> AoCResult {
         .take(8)
         .map(|d| char::from_digit((*d) as u32, 10).unwrap())
         .collect::<String>();
-    AoCResult::Str(res)
+    Ok(AoCResult::Str(res))
 }
 
-pub fn solve_part2(input: &String) -> AoCResult {
-    let mut data = input
-        .trim()
-        .chars()
-        .map(|c| c.to_digit(10).unwrap() as u8)
-        .collect_vec();
+pub fn solve_part2(input: &str) -> Result<AoCResult, AoCError> {
+    let mut data = parse_digits(input)?;
 
     // Copy data 9999 times
     let orig_len = data.len();
@@ -71,15 +62,15 @@ pub fn solve_part2(input: &String) -> AoCResult {
     }
 
     // Because the pattern at each position x starts with (x-1) zeroes, earlier numbers do not influence later values:
-    let offset: usize = data
-        .iter()
-        .take(7)
-        .map(|d| char::from_digit((*d) as u32, 10).unwrap())
-        .collect::<String>()
-        .parse()
-        .unwrap();
+    if orig_len < 7 {
+        return Err(AoCError::NoSolution);
+    }
+    let offset = data.iter().take(7).fold(0usize, |acc, d| acc * 10 + *d as usize);
 
     // Drop everything before the offset:
+    if offset + 8 > data.len() {
+        return Err(AoCError::NoSolution);
+    }
     data = data.into_iter().skip(offset).collect();
 
     // Assuming the offset is much larger than the number of remaining elements, the rest of the elements
@@ -108,7 +99,20 @@ pub fn solve_part2(input: &String) -> AoCResult {
         .take(8)
         .map(|d| char::from_digit((*d) as u32, 10).unwrap())
         .collect::<String>();
-    AoCResult::Str(res)
+    Ok(AoCResult::Str(res))
+}
+
+fn parse_digits(input: &str) -> Result<Vec<u8>, AoCError> {
+    input
+        .trim()
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            c.to_digit(10)
+                .map(|d| d as u8)
+                .ok_or_else(|| AoCError::parse_error(1, i + 1, format!("'{}' is not a digit", c)))
+        })
+        .collect()
 }
 
 #[cfg(test)]
@@ -118,42 +122,49 @@ mod tests {
     #[test]
     fn part1_example1() {
         let input = String::from("80871224585914546619083218645595");
-        let result = solve_part1(&input);
-        assert_eq!(String::from(result), "24176176");
+        let result = solve_part1(&input).unwrap();
+        assert_eq!(String::try_from(result).unwrap(), "24176176");
     }
 
     #[test]
     fn part1_example2() {
         let input = String::from("19617804207202209144916044189917");
-        let result = solve_part1(&input);
-        assert_eq!(String::from(result), "73745418");
+        let result = solve_part1(&input).unwrap();
+        assert_eq!(String::try_from(result).unwrap(), "73745418");
     }
 
     #[test]
     fn part1_example3() {
         let input = String::from("69317163492948606335995924319873");
-        let result = solve_part1(&input);
-        assert_eq!(String::from(result), "52432133");
+        let result = solve_part1(&input).unwrap();
+        assert_eq!(String::try_from(result).unwrap(), "52432133");
     }
 
     #[test]
     fn part2_example1() {
         let input = String::from("03036732577212944063491565474664");
-        let result = solve_part2(&input);
-        assert_eq!(String::from(result), "84462026");
+        let result = solve_part2(&input).unwrap();
+        assert_eq!(String::try_from(result).unwrap(), "84462026");
     }
 
     #[test]
     fn part2_example2() {
         let input = String::from("02935109699940807407585447034323");
-        let result = solve_part2(&input);
-        assert_eq!(String::from(result), "78725270");
+        let result = solve_part2(&input).unwrap();
+        assert_eq!(String::try_from(result).unwrap(), "78725270");
     }
 
     #[test]
     fn part2_example3() {
         let input = String::from("03081770884921959731165446850517");
-        let result = solve_part2(&input);
-        assert_eq!(String::from(result), "53553731");
+        let result = solve_part2(&input).unwrap();
+        assert_eq!(String::try_from(result).unwrap(), "53553731");
+    }
+
+    #[test]
+    fn part1_invalid_input() {
+        let input = String::from("8087122x");
+        let result = solve_part1(&input);
+        assert!(matches!(result, Err(AoCError::ParseError { line: 1, column: 8, .. })));
     }
 }
@@ -1,10 +1,14 @@
-use aoc_core::AoCResult;
+use aoc_core::{AoCError, AoCResult, PuzzleInput};
 use crate::shared::intcode::{IntcodeProgram, RunMode};
 
-aoc_core::make_day!(Day17);
+aoc_core::make_day!(Day17, 2019, 17, parsed: IntcodeProgram);
 
-pub fn solve_part1(input: &String) -> AoCResult {
-    let mut prgm = IntcodeProgram::new(input, None);
+pub fn parse(input: &PuzzleInput) -> Result<IntcodeProgram, AoCError> {
+    IntcodeProgram::new(input, None)
+}
+
+pub fn solve_part1(prgm: &IntcodeProgram) -> Result<AoCResult, AoCError> {
+    let mut prgm = prgm.clone();
     prgm.run(RunMode::Free);
 
     let mut camera_view: Vec<String> = vec![];
@@ -15,36 +19,35 @@ pub fn solve_part1(input: &String) -> AoCResult {
             .map(|x| (*x as u8) as char)
             .collect::<String>();
 
-        println!("{}", camera_line);
+        aoc_core::trace!("{}", camera_line);
         camera_view.push(camera_line);
     }
 
     let mut alignment_sum = 0;
+    let width = camera_view.first().map_or(0, |line| line.len());
     for y in 0..camera_view.len() {
         if y == 0 { continue; }
-        for x in 0..camera_view.first().unwrap().len() {
+        for x in 0..width {
             if x == 0 { continue; }
 
             // If current position is scaffold:
             if let Some(pos) = camera_view[y].chars().nth(x) && pos == '#' {
                 // Check all neighbors to find an intersection:
-                if let Some(up) = camera_view.get(y - 1) && up.chars().nth(x).unwrap() == '#' {
-                    if let Some(left) = camera_view[y].chars().nth(x - 1) && left == '#' {
-                        if let Some(right) = camera_view[y].chars().nth(x + 1) && right == '#' {
-                            if let Some(down) = camera_view.get(y + 1) && down.chars().nth(x).unwrap() == '#' {
-                                alignment_sum += y * x;
-                            }
-                        }
-                    }
+                if let Some(up) = camera_view.get(y - 1) && up.chars().nth(x) == Some('#')
+                    && let Some(left) = camera_view[y].chars().nth(x - 1) && left == '#'
+                    && let Some(right) = camera_view[y].chars().nth(x + 1) && right == '#'
+                    && let Some(down) = camera_view.get(y + 1) && down.chars().nth(x) == Some('#')
+                {
+                    alignment_sum += y * x;
                 }
             }
         }
     }
 
-    AoCResult::Num(alignment_sum as u64)
+    Ok(AoCResult::Num(alignment_sum as u64))
 }
 
-pub fn solve_part2(input: &String) -> AoCResult {
+pub fn solve_part2(prgm: &IntcodeProgram) -> Result<AoCResult, AoCError> {
     // Manually solved for commands to traverse the scaffold and split into subroutines:
     // L,6,R,12,L,4,L,6,R,6,L,6,R,12,R,6,L,6,R,12,L,6,L,10,L,10,R,6,L,6,R,12,L,4,L,6,R,6,L,6,R,12,L,6,L,10,L,10,R,6,L,6,R,12,L,4,L,6,R,6,L,6,R,12,L,6,L,10,L,10,R,6
     // ------A-------                                               ------A-------                                  ------A-------
@@ -60,7 +63,7 @@ pub fn solve_part2(input: &String) -> AoCResult {
 
     let func_main = "A,B,B,C,A,B,C,A,B,C";
 
-    let mut prgm = IntcodeProgram::new(input, None);
+    let mut prgm = prgm.clone();
     prgm.write(0, 2);
 
     // Provide routines as ascii code:
@@ -74,10 +77,30 @@ pub fn solve_part2(input: &String) -> AoCResult {
     }
 
     // Disable video feed:
-    prgm.input.push_back(('n' as u8) as i64);
+    prgm.input.push_back(b'n' as i64);
     prgm.input.push_back(10);
 
     prgm.run(RunMode::Free);
-    let res = *prgm.output.last().unwrap();
-    AoCResult::Num(res as u64)
+    let res = *prgm.output.last().ok_or(AoCError::NoSolution)?;
+    Ok(AoCResult::Int(res))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_uneven_camera_view() {
+        // Outputs "#", "###" and ".#." as camera lines
+        let input = PuzzleInput::from("104,35,104,10,104,35,104,35,104,35,104,10,104,46,104,35,104,46,99");
+        let result = solve_part1(&parse(&input).unwrap()).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 0);
+    }
+
+    #[test]
+    fn part2_no_output() {
+        let input = PuzzleInput::from("99");
+        let result = solve_part2(&parse(&input).unwrap());
+        assert!(matches!(result, Err(AoCError::NoSolution)));
+    }
 }
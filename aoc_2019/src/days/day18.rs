@@ -1,4 +1,4 @@
-use aoc_core::AoCResult;
+use aoc_core::{AoCError, AoCResult, PuzzleInput};
 use crate::shared::geometry::Point;
 use itertools::Itertools;
 use std::cmp::Ordering;
@@ -8,18 +8,19 @@ use std::{
     ops::Index,
 };
 
-aoc_core::make_day!(Day18);
+aoc_core::make_day!(Day18, 2019, 18);
 
-pub fn solve_part1(input: &String) -> AoCResult {
+pub fn solve_part1(input: &PuzzleInput) -> Result<AoCResult, AoCError> {
     // All keys that can be collected in the maze:
     let all_keys = input.chars().fold(0u32, |acc, c| match c {
-        'a'..='z' => acc | 1u32 << (c as u8) - ('a' as u8),
+        'a'..='z' => acc | 1u32 << ((c as u8) - b'a'),
         _ => acc,
     });
 
     // Parse the maze:
+    check_tiles(input)?;
     let maze = Maze::new(input);
-    let start = maze.find('@').unwrap();
+    let start = maze.find('@').ok_or(AoCError::NoSolution)?;
 
     // Explore maze via BFS
     let mut bfs_queue = VecDeque::<(MazeState, u64)>::new();
@@ -32,7 +33,7 @@ pub fn solve_part1(input: &String) -> AoCResult {
     ));
 
     // Check all possible directions at each locations to continue exploration:
-    let nesw = vec![
+    let nesw = [
         Point::new(0, -1),
         Point::new(1, 0),
         Point::new(0, 1),
@@ -41,14 +42,14 @@ pub fn solve_part1(input: &String) -> AoCResult {
 
     // Locations visited with keys collected at this point and the distance travelled to reach this state:
     let mut visited = HashMap::<MazeState, u64>::new();
-    let mut dist_for_all_keys = 0;
+    let mut dist_for_all_keys = None;
 
     while !bfs_queue.is_empty() {
         let (cur_state, dist) = bfs_queue.pop_front().unwrap();
 
         // First time all keys are collected stop the search:
         if cur_state.keys == all_keys {
-            dist_for_all_keys = dist;
+            dist_for_all_keys = Some(dist);
             break;
         }
 
@@ -61,12 +62,12 @@ pub fn solve_part1(input: &String) -> AoCResult {
             match tile {
                 'a'..='z' => {
                     // Key picked up: update state
-                    let key_collected = 1u32 << (tile.to_ascii_lowercase() as u8) - ('a' as u8);
+                    let key_collected = 1u32 << ((tile.to_ascii_lowercase() as u8) - b'a');
                     next_keys |= key_collected;
                 }
                 'A'..='Z' => {
                     // Door:
-                    let key_needed = 1u32 << (tile.to_ascii_lowercase() as u8) - ('a' as u8);
+                    let key_needed = 1u32 << ((tile.to_ascii_lowercase() as u8) - b'a');
                     if cur_state.keys & key_needed == 0 {
                         // Key not picked up, cannot move in this direction yet
                         continue;
@@ -103,17 +104,25 @@ pub fn solve_part1(input: &String) -> AoCResult {
         }
     }
 
-    AoCResult::Num(dist_for_all_keys)
+    dist_for_all_keys.map(AoCResult::Num).ok_or(AoCError::NoSolution)
 }
 
-pub fn solve_part2(input: &String) -> AoCResult {
+pub fn solve_part2(input: &PuzzleInput) -> Result<AoCResult, AoCError> {
     let all_keys = input.chars().fold(0u32, |acc, c| match c {
-        'a'..='z' => acc | 1u32 << (c as u8) - ('a' as u8),
+        'a'..='z' => acc | 1u32 << ((c as u8) - b'a'),
         _ => acc,
     });
 
+    check_tiles(input)?;
     let maze: Maze<'_> = Maze::new(input);
-    let start = maze.find('@').unwrap();
+    let start = maze.find('@').ok_or(AoCError::NoSolution)?;
+
+    // The start is split into four, so it has to be surrounded by open tiles:
+    for (dx, dy) in (-1..=1).cartesian_product(-1..=1) {
+        if !matches!(maze[&start.add(&Point::new(dx, dy))], b'.' | b'@') {
+            return Err(AoCError::NoSolution);
+        }
+    }
 
     // Pre-calculate all distances between keys. Maze will be processed by hopping from key to key.
     let mut key_distances = maze.get_key_distances();
@@ -123,7 +132,7 @@ pub fn solve_part2(input: &String) -> AoCResult {
     key_distances.entry('@').and_modify(|e| {
         for (other_key, (_, dist)) in e {
             if other_key != &'@' {
-                *dist = *dist - 2;
+                *dist -= 2;
             }
         }
     });
@@ -132,9 +141,9 @@ pub fn solve_part2(input: &String) -> AoCResult {
     // ...         @#@
     // .@.   -->   ###
     // ...         @#@
-    let maze_width = maze.width() + 2; // include \r\n linebreaks in original input into length:
+    let maze_width = maze.width() + 1; // include \n linebreaks in original input into length:
     let start_index = (maze_width * (start.y as usize)) + (start.x as usize);
-    let mut mod_input = input.clone();
+    let mut mod_input = input.to_string();
     mod_input.replace_range(
         (start_index - maze_width - 1)..=(start_index - maze_width + 1),
         "@#@",
@@ -191,40 +200,35 @@ pub fn solve_part2(input: &String) -> AoCResult {
 
     // Locations visited with keys collected at this point and the distance travelled to reach this state:
     let mut visited = HashMap::<MazeState2, u64>::new();
-    let mut dist_for_all_keys = 0;
+    let mut dist_for_all_keys = None;
 
     while !priority_queue.is_empty() {
         let cur_state = priority_queue.pop().unwrap();
 
         // First time all keys are collected stop the search:
         if cur_state.keys == all_keys {
-            dist_for_all_keys = cur_state.total_dist;
+            dist_for_all_keys = Some(cur_state.total_dist);
             break;
         }
 
         // Try moving each robot individually:
-        for robot in 0..4 {
+        for (robot, robot_keys) in keys_by_robot.iter().enumerate() {
             let last_key = cur_state.last_key[robot];
 
             // Try keys that have not been collected yet and can be reached with the currently collected keys:
-            for next_key in keys_by_robot[robot].iter().filter(|k| {
+            // (keys in a corner that is not connected to the robot's start are never reached)
+            for (next_key, steps_to_key) in robot_keys.iter().filter_map(|k| {
                 let key_has_not_been_collected =
-                    cur_state.keys & (1u32 << ((**k as u8) - ('a' as u8))) == 0;
-                let blocked_by_doors = key_distances.get(&last_key).unwrap().get(k).unwrap().0;
+                    cur_state.keys & (1u32 << ((*k as u8) - b'a')) == 0;
+                let (blocked_by_doors, steps) = *key_distances.get(&last_key)?.get(k)?;
                 let can_unlock_all_doors = (blocked_by_doors & cur_state.keys) == blocked_by_doors;
-                key_has_not_been_collected && can_unlock_all_doors
+                (key_has_not_been_collected && can_unlock_all_doors).then_some((k, steps))
             }) {
                 // New state:
                 let mut next_state = cur_state.clone();
-                next_state.keys = cur_state.keys | (1u32 << ((*next_key as u8) - ('a' as u8)));
+                next_state.keys = cur_state.keys | (1u32 << ((*next_key as u8) - b'a'));
 
                 // Add distance to the next key to total distance:
-                let steps_to_key = key_distances
-                    .get(&next_state.last_key[robot])
-                    .unwrap()
-                    .get(next_key)
-                    .unwrap()
-                    .1;
                 let total_dist = cur_state.total_dist + steps_to_key;
                 next_state.total_dist = total_dist;
 
@@ -250,7 +254,7 @@ pub fn solve_part2(input: &String) -> AoCResult {
         }
     }
 
-    AoCResult::Num(dist_for_all_keys)
+    dist_for_all_keys.map(AoCResult::Num).ok_or(AoCError::NoSolution)
 }
 
 pub struct Maze<'a> {
@@ -258,14 +262,14 @@ pub struct Maze<'a> {
 }
 
 impl<'a> Maze<'a> {
-    fn new(input: &'a String) -> Self {
+    fn new(input: &'a str) -> Self {
         Maze {
             cells: input.trim_end().lines().map(|l| l.trim_end()).collect_vec(),
         }
     }
 
     fn width(&self) -> usize {
-        self.cells[0].len()
+        self.cells.first().map_or(0, |row| row.len())
     }
 
     fn find(&self, c: char) -> Option<Point> {
@@ -284,7 +288,7 @@ impl<'a> Maze<'a> {
         // Key-to-key positions, with doors (u32) and distance (u64) between them
         let mut key_distances = HashMap::<char, HashMap<char, (u32, u64)>>::new();
 
-        let nesw = vec![
+        let nesw = [
             Point::new(0, -1),
             Point::new(1, 0),
             Point::new(0, 1),
@@ -352,7 +356,7 @@ impl<'a> Maze<'a> {
                         }
                         'A'..='Z' => {
                             // Door:
-                            let door = 1u32 << (tile.to_ascii_lowercase() as u8) - ('a' as u8);
+                            let door = 1u32 << ((tile.to_ascii_lowercase() as u8) - b'a');
                             next_doors |= door;
                         }
                         '#' => {
@@ -390,12 +394,13 @@ impl<'a> Maze<'a> {
     }
 }
 
+// Everything outside of the maze is a wall
 impl<'a> Index<(usize, usize)> for Maze<'a> {
     type Output = u8;
 
     #[inline]
     fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
-        &self.cells[y].as_bytes()[x]
+        self.cells.get(y).and_then(|row| row.as_bytes().get(x)).unwrap_or(&b'#')
     }
 }
 
@@ -404,10 +409,23 @@ impl<'a> Index<&Point> for Maze<'a> {
 
     #[inline]
     fn index(&self, p: &Point) -> &Self::Output {
-        &self.cells[p.y as usize].as_bytes()[p.x as usize]
+        if p.x < 0 || p.y < 0 {
+            return &b'#';
+        }
+        &self[(p.x as usize, p.y as usize)]
     }
 }
 
+// The maze has to be rectangular, with only walls, open tiles, keys, doors and the start
+fn check_tiles(input: &PuzzleInput) -> Result<(), AoCError> {
+    for (y, row) in input.grid()?.iter().enumerate() {
+        if let Some(x) = row.iter().position(|c| !matches!(c, '#' | '.' | '@' | 'a'..='z' | 'A'..='Z')) {
+            return Err(AoCError::parse_error(y + 1, x + 1, format!("unknown tile '{}'", row[x])));
+        }
+    }
+    Ok(())
+}
+
 #[derive(Clone, PartialEq, Eq, Hash)]
 struct MazeState {
     pos: Point, // position inside the maze
@@ -461,60 +479,73 @@ mod tests {
 
     #[test]
     fn part1_example1() {
-        let input = String::from("#########\r\n#b.A.@.a#\r\n#########");
-        let result = solve_part1(&input);
-        assert_eq!(u64::from(result), 8);
+        let input = PuzzleInput::from("#########\r\n#b.A.@.a#\r\n#########");
+        let result = solve_part1(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 8);
     }
 
     #[test]
     fn part1_example2() {
-        let input = String::from("########################\r\n#f.D.E.e.C.b.A.@.a.B.c.#\r\n######################.#\r\n#d.....................#\r\n########################");
-        let result = solve_part1(&input);
-        assert_eq!(u64::from(result), 86);
+        let input = PuzzleInput::from("########################\r\n#f.D.E.e.C.b.A.@.a.B.c.#\r\n######################.#\r\n#d.....................#\r\n########################");
+        let result = solve_part1(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 86);
     }
 
     #[test]
     fn part1_example3() {
-        let input = String::from("########################\r\n#...............b.C.D.f#\r\n#.######################\r\n#.....@.a.B.c.d.A.e.F.g#\r\n########################");
-        let result = solve_part1(&input);
-        assert_eq!(u64::from(result), 132);
+        let input = PuzzleInput::from("########################\r\n#...............b.C.D.f#\r\n#.######################\r\n#.....@.a.B.c.d.A.e.F.g#\r\n########################");
+        let result = solve_part1(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 132);
     }
 
     #[test]
     fn part1_example4() {
-        let input = String::from("#################\r\n#i.G..c...e..H.p#\r\n########.########\r\n#j.A..b...f..D.o#\r\n########@########\r\n#k.E..a...g..B.n#\r\n########.########\r\n#l.F..d...h..C.m#\r\n#################");
-        let result = solve_part1(&input);
-        assert_eq!(u64::from(result), 136);
+        let input = PuzzleInput::from("#################\r\n#i.G..c...e..H.p#\r\n########.########\r\n#j.A..b...f..D.o#\r\n########@########\r\n#k.E..a...g..B.n#\r\n########.########\r\n#l.F..d...h..C.m#\r\n#################");
+        let result = solve_part1(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 136);
     }
 
     #[test]
     fn part1_example5() {
-        let input = String::from("########################\r\n#@..............ac.GI.b#\r\n###d#e#f################\r\n###A#B#C################\r\n###g#h#i################\r\n########################");
-        let result = solve_part1(&input);
-        assert_eq!(u64::from(result), 81);
+        let input = PuzzleInput::from("########################\r\n#@..............ac.GI.b#\r\n###d#e#f################\r\n###A#B#C################\r\n###g#h#i################\r\n########################");
+        let result = solve_part1(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 81);
     }
 
     #[test]
     fn part2_example1() {
-        let input = String::from("#######\r\n#a.#Cd#\r\n##...##\r\n##.@.##\r\n##...##\r\n#cB#Ab#\r\n#######");
-        let result = solve_part2(&input);
-        assert_eq!(u64::from(result), 8);
+        let input = PuzzleInput::from("#######\r\n#a.#Cd#\r\n##...##\r\n##.@.##\r\n##...##\r\n#cB#Ab#\r\n#######");
+        let result = solve_part2(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 8);
     }
 
     #[test]
     fn part2_example2() {
-        let input = String::from("###############\r\n#d.ABC.#.....a#\r\n######...######\r\n######.@.######\r\n######...######\r\n#b.....#.....c#\r\n###############");
+        let input = PuzzleInput::from("###############\r\n#d.ABC.#.....a#\r\n######...######\r\n######.@.######\r\n######...######\r\n#b.....#.....c#\r\n###############");
+        let result = solve_part2(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 24);
+    }
+
+    #[test]
+    fn part1_invalid_input() {
+        let input = PuzzleInput::from("#####\r\n#@.a#\r\n#.?.#\r\n#####");
+        let result = solve_part1(&input);
+        assert!(matches!(result, Err(AoCError::ParseError { line: 3, column: 3, .. })));
+    }
+
+    #[test]
+    fn part2_start_at_wall() {
+        let input = PuzzleInput::from("#####\r\n#@.a#\r\n#####");
         let result = solve_part2(&input);
-        assert_eq!(u64::from(result), 24);
+        assert!(matches!(result, Err(AoCError::NoSolution)));
     }
 
-    // removed because the maze is not perfectly split into corners
-    // fn part2_example3() { }
+    // part2_example3 is in examples/2019/day18, ignored because the maze is not perfectly split into corners
 
     #[test]
     fn part2_example4() {
-        let input = String::from("#############\r\n#g#f.D#..h#l#\r\n#F###e#E###.#\r\n#dCba...BcIJ#\r\n#####.@.#####\r\n#nK.L...G...#\r\n#M###N#H###.#\r\n#o#m..#i#jk.#\r\n#############");
-        let result = solve_part2(&input);
-        assert_eq!(u64::from(result), 72);
+        let input = PuzzleInput::from("#############\r\n#g#f.D#..h#l#\r\n#F###e#E###.#\r\n#dCba...BcIJ#\r\n#####.@.#####\r\n#nK.L...G...#\r\n#M###N#H###.#\r\n#o#m..#i#jk.#\r\n#############");
+        let result = solve_part2(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 72);
     }
 }
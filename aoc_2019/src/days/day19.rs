@@ -1,16 +1,24 @@
-use aoc_core::AoCResult;
+use aoc_core::{AoCError, AoCResult, Params, PuzzleInput};
 use crate::shared::intcode::{IntcodeProgram, RunMode};
 
-aoc_core::make_day!(Day19);
+aoc_core::make_day!(Day19, 2019, 19, parsed: IntcodeProgram, params: [area_size = "50", ship_size = "100"]);
 
-pub fn solve_part1(input: &String) -> AoCResult {
+// Largest coordinate checked when searching for a place for the ship
+const SEARCH_LIMIT: i64 = 100_000;
+
+pub fn parse(input: &PuzzleInput) -> Result<IntcodeProgram, AoCError> {
+    IntcodeProgram::new(input, None)
+}
+
+pub fn solve_part1(prgm: &IntcodeProgram, params: &Params) -> Result<AoCResult, AoCError> {
+    let area_size = params.get_positive::<i64>("area_size")?;
     let mut points_affected_by_tractor_beam = 0;
-    let mut lines = Vec::<String>::new(); // for debug print
+    let mut lines = Vec::<String>::new(); // for debug output
 
-    for y in 0..50 {
+    for y in 0..area_size {
         let mut line = String::new();
-        for x in 0..50 {
-            if is_point_in_tractor_beam(&input, x, y) {
+        for x in 0..area_size {
+            if is_point_in_tractor_beam(prgm, x, y)? {
                 points_affected_by_tractor_beam += 1;
                 line.push('#');
             } else {
@@ -21,24 +29,34 @@ pub fn solve_part1(input: &String) -> AoCResult {
     }
 
     for line in lines {
-        println!("{}", line);
+        aoc_core::trace!("{}", line);
     }
 
-    AoCResult::Num(points_affected_by_tractor_beam)
+    Ok(AoCResult::Num(points_affected_by_tractor_beam))
 }
 
-pub fn solve_part2(input: &String) -> AoCResult {
+pub fn solve_part2(prgm: &IntcodeProgram, params: &Params) -> Result<AoCResult, AoCError> {
+    let ship_size = params.get::<i64>("ship_size")?;
+    if ship_size < 2 {
+        return Err(AoCError::InvalidParameter("ship_size must be at least 2".to_string()));
+    }
+
     // Start somewhere to the left of the tractor beam:
     let mut x = 50;
     let mut y = 100;
 
     loop {
+        // Give up if the beam never gets wide enough:
+        if x > SEARCH_LIMIT || y > SEARCH_LIMIT {
+            return Err(AoCError::NoSolution);
+        }
+
         // Check if bounding box is fully inside the tractor beam:
         let mut square_fits = true;
-        'bounds_loop: for dx in 0..100 {
-            let y_step = if dx == 0 || dx == 99 { 1 } else { 99 };
-            for dy in (0..100).step_by(y_step) {
-                if !is_point_in_tractor_beam(&input, x + dx, y + dy) {
+        'bounds_loop: for dx in 0..ship_size {
+            let y_step = if dx == 0 || dx == ship_size - 1 { 1 } else { ship_size - 1 };
+            for dy in (0..ship_size).step_by(y_step as usize) {
+                if !is_point_in_tractor_beam(prgm, x + dx, y + dy)? {
                     square_fits = false;
                     if dy == 0 && dx == 0 {
                         // Move right until the top left corner is within the tractor beam:
@@ -47,7 +65,7 @@ pub fn solve_part2(input: &String) -> AoCResult {
                         // Left edge is not fully within the beam:
 
                         // Is the top right corner within the beam?
-                        if is_point_in_tractor_beam(&input, x + 99, y + dy) {
+                        if is_point_in_tractor_beam(prgm, x + ship_size - 1, y + dy)? {
                             // Move further to the left
                             x += 1;
                         } else {
@@ -69,13 +87,34 @@ pub fn solve_part2(input: &String) -> AoCResult {
         }
     }
 
-    AoCResult::Num((x * 10000 + y) as u64)
+    Ok(AoCResult::Int(x * 10000 + y))
 }
 
-fn is_point_in_tractor_beam(input: &String, x: i64, y: i64) -> bool {
-    let mut prgm: IntcodeProgram = IntcodeProgram::new(input, None);
+fn is_point_in_tractor_beam(drone_prgm: &IntcodeProgram, x: i64, y: i64) -> Result<bool, AoCError> {
+    let mut prgm = drone_prgm.clone();
     prgm.input.push_back(x);
     prgm.input.push_back(y);
     prgm.run(RunMode::Free);
-    prgm.output[0] == 1
+    Ok(*prgm.output.first().ok_or(AoCError::NoSolution)? == 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::IoContext;
+
+    #[test]
+    fn part1_no_output() {
+        let input = PuzzleInput::from("99");
+        let result = Day19 {}.run_part1(&input, &Params::from([("area_size", "5")]), &mut IoContext::silent());
+        assert!(matches!(result, Err(AoCError::NoSolution)));
+    }
+
+    #[test]
+    fn part2_no_beam() {
+        // Drone that never reports a beam
+        let input = PuzzleInput::from("104,0,99");
+        let result = Day19 {}.run_part2(&input, &Params::from([("ship_size", "2")]), &mut IoContext::silent());
+        assert!(matches!(result, Err(AoCError::NoSolution)));
+    }
 }
@@ -1,13 +1,13 @@
-use aoc_core::AoCResult;
+use aoc_core::{AoCError, AoCResult, PuzzleInput};
 use crate::shared::geometry::Point;
 use std::{collections::{HashMap, VecDeque}};
 use itertools::Itertools;
 
-aoc_core::make_day!(Day20);
+aoc_core::make_day!(Day20, 2019, 20);
 
-pub fn solve_part1(input: &String) -> AoCResult {
-    let input_width = input.lines().next().unwrap().len() + 2; // add \r\n back to the length of a line
-    let portal_list = get_portal_list(&input, input_width);
+pub fn solve_part1(input: &PuzzleInput) -> Result<AoCResult, AoCError> {
+    let input_width = get_input_width(input)?;
+    let portal_list = get_portal_list(input, input_width);
     
     let mut portals_map = HashMap::<Point, Point>::new();
     for portal in portal_list.iter() {
@@ -16,21 +16,21 @@ pub fn solve_part1(input: &String) -> AoCResult {
         }
     }
 
-    let start = portal_list.iter().find(|p| p.name == "AA").unwrap().pos;
-    let end = portal_list.iter().find(|p| p.name == "ZZ").unwrap().pos;
+    let start = portal_list.iter().find(|p| p.name == "AA").ok_or(AoCError::NoSolution)?.pos;
+    let end = portal_list.iter().find(|p| p.name == "ZZ").ok_or(AoCError::NoSolution)?.pos;
 
     let mut visited = HashMap::<Point, u64>::new();
     let mut bfs_queue = VecDeque::<(Point, u64)>::new();
     bfs_queue.push_back((start, 0));
 
-    let nesw = vec![
+    let nesw = [
         Point::new(0, -1),
         Point::new(1, 0),
         Point::new(0, 1),
         Point::new(-1, 0),
     ];
 
-    let mut steps_to_zz = 0;
+    let mut steps_to_zz = None;
 
     while !bfs_queue.is_empty() {
         let (cur_pos, dist) = bfs_queue.pop_front().unwrap();
@@ -38,7 +38,7 @@ pub fn solve_part1(input: &String) -> AoCResult {
 
         // Check if the target location is reached:
         if cur_pos == end {
-            steps_to_zz = dist;
+            steps_to_zz = Some(dist);
             break;
         } else if let Some(portal) = portals_map.get(&cur_pos) {
             // Step through portal:
@@ -60,7 +60,7 @@ pub fn solve_part1(input: &String) -> AoCResult {
             let next_pos = cur_pos.add(dir);
 
             // Check if next pos is walkable:
-            if let Some(next_tile) = input.chars().nth((next_pos.y as usize) * input_width + (next_pos.x as usize))
+            if let Some(next_tile) = tile_at(input, input_width, &next_pos)
                 && next_tile == '.' {
                 if let Some(prev_dist) = visited.get_mut(&next_pos) {
                     if *prev_dist <= next_dist {
@@ -80,12 +80,12 @@ pub fn solve_part1(input: &String) -> AoCResult {
         }
     }
 
-    AoCResult::Num(steps_to_zz)
+    steps_to_zz.map(AoCResult::Num).ok_or(AoCError::NoSolution)
 }
 
-pub fn solve_part2(input: &String) -> AoCResult {
-    let input_width = input.lines().next().unwrap().len() + 2; // add \r\n back to the length of a line
-    let portal_list = get_portal_list(&input, input_width);
+pub fn solve_part2(input: &PuzzleInput) -> Result<AoCResult, AoCError> {
+    let input_width = get_input_width(input)?;
+    let portal_list = get_portal_list(input, input_width);
     
     let mut portals_map = HashMap::<Point, (PortalType, Point)>::new();
     for portal in portal_list.iter() {
@@ -94,21 +94,21 @@ pub fn solve_part2(input: &String) -> AoCResult {
         }
     }
 
-    let start = portal_list.iter().find(|p| p.name == "AA").unwrap().pos;
-    let end = portal_list.iter().find(|p| p.name == "ZZ").unwrap().pos;
+    let start = portal_list.iter().find(|p| p.name == "AA").ok_or(AoCError::NoSolution)?.pos;
+    let end = portal_list.iter().find(|p| p.name == "ZZ").ok_or(AoCError::NoSolution)?.pos;
 
     let mut visited = HashMap::<QueueState, u64>::new();
     let mut bfs_queue = VecDeque::<(QueueState, u64)>::new();
     bfs_queue.push_back((QueueState { pos: start, level: 0 }, 0));
 
-    let nesw = vec![
+    let nesw = [
         Point::new(0, -1),
         Point::new(1, 0),
         Point::new(0, 1),
         Point::new(-1, 0),
     ];
 
-    let mut steps_to_zz = 0;
+    let mut steps_to_zz = None;
 
     while !bfs_queue.is_empty() {
         let (cur_state, dist) = bfs_queue.pop_front().unwrap();
@@ -116,7 +116,7 @@ pub fn solve_part2(input: &String) -> AoCResult {
 
         // Check if the target location is reached:
         if cur_state.level == 0 && cur_state.pos == end {
-            steps_to_zz = dist;
+            steps_to_zz = Some(dist);
             break;
         } else if let Some((portal_type, portal_output)) = portals_map.get(&cur_state.pos) && 
             (portal_type == &PortalType::Inside || cur_state.level > 0) {
@@ -146,7 +146,7 @@ pub fn solve_part2(input: &String) -> AoCResult {
             let next_pos = cur_state.pos.add(dir);
 
             // Check if next pos is walkable (places of start and end are also walls if level is not 0)
-            if let Some(next_tile) = input.chars().nth((next_pos.y as usize) * input_width + (next_pos.x as usize))
+            if let Some(next_tile) = tile_at(input, input_width, &next_pos)
                 && next_tile == '.' && (next_pos != end || cur_state.level == 0) {
 
                 let next_state = QueueState { 
@@ -172,7 +172,7 @@ pub fn solve_part2(input: &String) -> AoCResult {
         }
     }
 
-    AoCResult::Num(steps_to_zz)
+    steps_to_zz.map(AoCResult::Num).ok_or(AoCError::NoSolution)
 }
 
 #[derive(Clone, Copy, PartialEq)]
@@ -187,30 +187,38 @@ struct Portal {
     portal_type: PortalType
 }
 
-fn get_portal_list(input: &String, input_width: usize) -> Vec<Portal> {
+// Length of a line of the maze, including its \n
+fn get_input_width(input: &PuzzleInput) -> Result<usize, AoCError> {
+    let first_line = input.lines().next().ok_or_else(|| AoCError::parse_error(1, 1, "expected maze"))?;
+    Ok(first_line.len() + 1)
+}
+
+fn get_portal_list(input: &str, input_width: usize) -> Vec<Portal> {
     input.chars()
         .enumerate()
-        .map(|(i, c)| {
+        // filters out None values
+        .filter_map(|(i, c)| {
             match c {
                 'A'..='Z' => {
                     // Check character above / Below / left / right:
+                    // (a letter next to a '.' without a second letter is not a portal)
                     let up = if i >= input_width { input.chars().nth(i - input_width) } else { None };
                     let down = input.chars().nth(i + input_width);
-                    let left = input.chars().nth(i - 1);
+                    let left = if i >= 1 { input.chars().nth(i - 1) } else { None };
                     let right = input.chars().nth(i + 1);
 
                     if let Some(up_char) = up && up_char == '.' {
                         // Portal above, name is this char and the char below
-                        Some((i - input_width, c, down.unwrap()))
+                        Some((i - input_width, c, down?))
                     } else if let Some(down_char) = down && down_char == '.' {
                         // Portal below, name is the char above and this char
-                        Some((i + input_width, up.unwrap(), c))
+                        Some((i + input_width, up?, c))
                     } else if let Some(left_char) = left && left_char == '.' {
                         // Portal to the left, name is this char and the char to the right
-                        Some((i - 1, c, right.unwrap()))
+                        Some((i - 1, c, right?))
                     } else if let Some(right_char) = right && right_char == '.' {
                         // Portal to the right, name is the char to the left and this char
-                        Some((i + 1, left.unwrap(), c))
+                        Some((i + 1, left?, c))
                     } else {
                         None
                     }
@@ -218,10 +226,9 @@ fn get_portal_list(input: &String, input_width: usize) -> Vec<Portal> {
                 _ => None,
             }
         })
-        .flatten()  // filters out None values
         .map(|(i, c1, c2)| {
             let portal_pos = Point::new((i % input_width) as i64, (i / input_width) as i64);
-            let portal_type = if portal_pos.y == 2 || portal_pos.y == (input.lines().count() - 3) as i64 || portal_pos.x == 2 || portal_pos.x == (input_width - 5) as i64 {
+            let portal_type = if portal_pos.y == 2 || portal_pos.y == input.lines().count() as i64 - 3 || portal_pos.x == 2 || portal_pos.x == input_width as i64 - 4 {
                 PortalType::Outside
             } else {
                 PortalType::Inside
@@ -230,12 +237,20 @@ fn get_portal_list(input: &String, input_width: usize) -> Vec<Portal> {
             Portal { 
                 name: format!("{}{}", c1, c2),
                 pos: portal_pos,
-                portal_type: portal_type
+                portal_type
             }
         })
         .collect_vec()
 }
 
+// Tile at the position, None outside of the maze
+fn tile_at(input: &str, input_width: usize, pos: &Point) -> Option<char> {
+    if pos.x < 0 || pos.y < 0 || pos.x as usize >= input_width {
+        return None;
+    }
+    input.chars().nth((pos.y as usize) * input_width + (pos.x as usize))
+}
+
 #[derive(Clone, PartialEq, Eq, Hash)]
 struct QueueState {
     pos: Point,
@@ -248,22 +263,43 @@ mod tests {
 
     #[test]
     fn part1_example1() {
-        let input = String::from("         A           \r\n         A           \r\n  #######.#########  \r\n  #######.........#  \r\n  #######.#######.#  \r\n  #######.#######.#  \r\n  #######.#######.#  \r\n  #####  B    ###.#  \r\nBC...##  C    ###.#  \r\n  ##.##       ###.#  \r\n  ##...DE  F  ###.#  \r\n  #####    G  ###.#  \r\n  #########.#####.#  \r\nDE..#######...###.#  \r\n  #.#########.###.#  \r\nFG..#########.....#  \r\n  ###########.#####  \r\n             Z       \r\n             Z       ");
-        let result = solve_part1(&input);
-        assert_eq!(u64::from(result), 23);
+        let input = PuzzleInput::from("         A           \r\n         A           \r\n  #######.#########  \r\n  #######.........#  \r\n  #######.#######.#  \r\n  #######.#######.#  \r\n  #######.#######.#  \r\n  #####  B    ###.#  \r\nBC...##  C    ###.#  \r\n  ##.##       ###.#  \r\n  ##...DE  F  ###.#  \r\n  #####    G  ###.#  \r\n  #########.#####.#  \r\nDE..#######...###.#  \r\n  #.#########.###.#  \r\nFG..#########.....#  \r\n  ###########.#####  \r\n             Z       \r\n             Z       ");
+        let result = solve_part1(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 23);
+    }
+
+    #[test]
+    fn part1_example1_lf() {
+        let input = PuzzleInput::from("         A           \n         A           \n  #######.#########  \n  #######.........#  \n  #######.#######.#  \n  #######.#######.#  \n  #######.#######.#  \n  #####  B    ###.#  \nBC...##  C    ###.#  \n  ##.##       ###.#  \n  ##...DE  F  ###.#  \n  #####    G  ###.#  \n  #########.#####.#  \nDE..#######...###.#  \n  #.#########.###.#  \nFG..#########.....#  \n  ###########.#####  \n             Z       \n             Z       ");
+        let result = solve_part1(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 23);
     }
 
     #[test]
     fn part1_example2() {
-        let input = String::from("                   A               \r\n                   A               \r\n  #################.#############  \r\n  #.#...#...................#.#.#  \r\n  #.#.#.###.###.###.#########.#.#  \r\n  #.#.#.......#...#.....#.#.#...#  \r\n  #.#########.###.#####.#.#.###.#  \r\n  #.............#.#.....#.......#  \r\n  ###.###########.###.#####.#.#.#  \r\n  #.....#        A   C    #.#.#.#  \r\n  #######        S   P    #####.#  \r\n  #.#...#                 #......VT\r\n  #.#.#.#                 #.#####  \r\n  #...#.#               YN....#.#  \r\n  #.###.#                 #####.#  \r\nDI....#.#                 #.....#  \r\n  #####.#                 #.###.#  \r\nZZ......#               QG....#..AS\r\n  ###.###                 #######  \r\nJO..#.#.#                 #.....#  \r\n  #.#.#.#                 ###.#.#  \r\n  #...#..DI             BU....#..LF\r\n  #####.#                 #.#####  \r\nYN......#               VT..#....QG\r\n  #.###.#                 #.###.#  \r\n  #.#...#                 #.....#  \r\n  ###.###    J L     J    #.#.###  \r\n  #.....#    O F     P    #.#...#  \r\n  #.###.#####.#.#####.#####.###.#  \r\n  #...#.#.#...#.....#.....#.#...#  \r\n  #.#####.###.###.#.#.#########.#  \r\n  #...#.#.....#...#.#.#.#.....#.#  \r\n  #.###.#####.###.###.#.#.#######  \r\n  #.#.........#...#.............#  \r\n  #########.###.###.#############  \r\n           B   J   C               \r\n           U   P   P               ");
+        let input = PuzzleInput::from("                   A               \r\n                   A               \r\n  #################.#############  \r\n  #.#...#...................#.#.#  \r\n  #.#.#.###.###.###.#########.#.#  \r\n  #.#.#.......#...#.....#.#.#...#  \r\n  #.#########.###.#####.#.#.###.#  \r\n  #.............#.#.....#.......#  \r\n  ###.###########.###.#####.#.#.#  \r\n  #.....#        A   C    #.#.#.#  \r\n  #######        S   P    #####.#  \r\n  #.#...#                 #......VT\r\n  #.#.#.#                 #.#####  \r\n  #...#.#               YN....#.#  \r\n  #.###.#                 #####.#  \r\nDI....#.#                 #.....#  \r\n  #####.#                 #.###.#  \r\nZZ......#               QG....#..AS\r\n  ###.###                 #######  \r\nJO..#.#.#                 #.....#  \r\n  #.#.#.#                 ###.#.#  \r\n  #...#..DI             BU....#..LF\r\n  #####.#                 #.#####  \r\nYN......#               VT..#....QG\r\n  #.###.#                 #.###.#  \r\n  #.#...#                 #.....#  \r\n  ###.###    J L     J    #.#.###  \r\n  #.....#    O F     P    #.#...#  \r\n  #.###.#####.#.#####.#####.###.#  \r\n  #...#.#.#...#.....#.....#.#...#  \r\n  #.#####.###.###.#.#.#########.#  \r\n  #...#.#.....#...#.#.#.#.....#.#  \r\n  #.###.#####.###.###.#.#.#######  \r\n  #.#.........#...#.............#  \r\n  #########.###.###.#############  \r\n           B   J   C               \r\n           U   P   P               ");
+        let result = solve_part1(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 58);
+    }
+
+    #[test]
+    fn part1_empty_input() {
+        let input = PuzzleInput::from("");
+        let result = solve_part1(&input);
+        assert!(matches!(result, Err(AoCError::ParseError { line: 1, column: 1, .. })));
+    }
+
+    #[test]
+    fn part1_missing_exit() {
+        let input = PuzzleInput::from("  A  \r\n  A  \r\n##.##\r\nZ...#\r\n#####");
         let result = solve_part1(&input);
-        assert_eq!(u64::from(result), 58);
+        assert!(matches!(result, Err(AoCError::NoSolution)));
     }
 
     #[test]
     fn part2_example1() {
-        let input = String::from("             Z L X W       C                 \r\n             Z P Q B       K                 \r\n  ###########.#.#.#.#######.###############  \r\n  #...#.......#.#.......#.#.......#.#.#...#  \r\n  ###.#.#.#.#.#.#.#.###.#.#.#######.#.#.###  \r\n  #.#...#.#.#...#.#.#...#...#...#.#.......#  \r\n  #.###.#######.###.###.#.###.###.#.#######  \r\n  #...#.......#.#...#...#.............#...#  \r\n  #.#########.#######.#.#######.#######.###  \r\n  #...#.#    F       R I       Z    #.#.#.#  \r\n  #.###.#    D       E C       H    #.#.#.#  \r\n  #.#...#                           #...#.#  \r\n  #.###.#                           #.###.#  \r\n  #.#....OA                       WB..#.#..ZH\r\n  #.###.#                           #.#.#.#  \r\nCJ......#                           #.....#  \r\n  #######                           #######  \r\n  #.#....CK                         #......IC\r\n  #.###.#                           #.###.#  \r\n  #.....#                           #...#.#  \r\n  ###.###                           #.#.#.#  \r\nXF....#.#                         RF..#.#.#  \r\n  #####.#                           #######  \r\n  #......CJ                       NM..#...#  \r\n  ###.#.#                           #.###.#  \r\nRE....#.#                           #......RF\r\n  ###.###        X   X       L      #.#.#.#  \r\n  #.....#        F   Q       P      #.#.#.#  \r\n  ###.###########.###.#######.#########.###  \r\n  #.....#...#.....#.......#...#.....#.#...#  \r\n  #####.#.###.#######.#######.###.###.#.#.#  \r\n  #.......#.......#.#.#.#.#...#...#...#.#.#  \r\n  #####.###.#####.#.#.#.#.###.###.#.###.###  \r\n  #.......#.....#.#...#...............#...#  \r\n  #############.#.#.###.###################  \r\n               A O F   N                     \r\n               A A D   M                     ");
-        let result = solve_part2(&input);
-        assert_eq!(u64::from(result), 396);
+        let input = PuzzleInput::from("             Z L X W       C                 \r\n             Z P Q B       K                 \r\n  ###########.#.#.#.#######.###############  \r\n  #...#.......#.#.......#.#.......#.#.#...#  \r\n  ###.#.#.#.#.#.#.#.###.#.#.#######.#.#.###  \r\n  #.#...#.#.#...#.#.#...#...#...#.#.......#  \r\n  #.###.#######.###.###.#.###.###.#.#######  \r\n  #...#.......#.#...#...#.............#...#  \r\n  #.#########.#######.#.#######.#######.###  \r\n  #...#.#    F       R I       Z    #.#.#.#  \r\n  #.###.#    D       E C       H    #.#.#.#  \r\n  #.#...#                           #...#.#  \r\n  #.###.#                           #.###.#  \r\n  #.#....OA                       WB..#.#..ZH\r\n  #.###.#                           #.#.#.#  \r\nCJ......#                           #.....#  \r\n  #######                           #######  \r\n  #.#....CK                         #......IC\r\n  #.###.#                           #.###.#  \r\n  #.....#                           #...#.#  \r\n  ###.###                           #.#.#.#  \r\nXF....#.#                         RF..#.#.#  \r\n  #####.#                           #######  \r\n  #......CJ                       NM..#...#  \r\n  ###.#.#                           #.###.#  \r\nRE....#.#                           #......RF\r\n  ###.###        X   X       L      #.#.#.#  \r\n  #.....#        F   Q       P      #.#.#.#  \r\n  ###.###########.###.#######.#########.###  \r\n  #.....#...#.....#.......#...#.....#.#...#  \r\n  #####.#.###.#######.#######.###.###.#.#.#  \r\n  #.......#.......#.#.#.#.#...#...#...#.#.#  \r\n  #####.###.#####.#.#.#.#.###.###.#.###.###  \r\n  #.......#.....#.#...#...............#...#  \r\n  #############.#.#.###.###################  \r\n               A O F   N                     \r\n               A A D   M                     ");
+        let result = solve_part2(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 396);
     }
 }
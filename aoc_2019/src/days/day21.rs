@@ -1,10 +1,14 @@
-use aoc_core::AoCResult;
+use aoc_core::{AoCError, AoCResult, PuzzleInput};
 use crate::shared::intcode::{IntcodeProgram, RunMode};
 
-aoc_core::make_day!(Day21);
+aoc_core::make_day!(Day21, 2019, 21, parsed: IntcodeProgram);
 
-pub fn solve_part1(input: &String) -> AoCResult {
-    let mut prgm = IntcodeProgram::new(input, None);
+pub fn parse(input: &PuzzleInput) -> Result<IntcodeProgram, AoCError> {
+    IntcodeProgram::new(input, None)
+}
+
+pub fn solve_part1(prgm: &IntcodeProgram) -> Result<AoCResult, AoCError> {
+    let mut prgm = prgm.clone();
     
     // Jump, if one of the three tiles before the robot contain a hole and the tile at distance 4 is solid ground
     prgm.input_ascii("NOT A J\n");
@@ -24,15 +28,15 @@ pub fn solve_part1(input: &String) -> AoCResult {
             .map(|x| (*x as u8) as char)
             .collect::<String>();
 
-        println!("{}", camera_line);
+        aoc_core::trace!("{}", camera_line);
         camera_view.push(camera_line);
     }
 
-    AoCResult::Num(*prgm.output.last().unwrap() as u64)
+    Ok(AoCResult::Int(*prgm.output.last().ok_or(AoCError::NoSolution)?))
 }
 
-pub fn solve_part2(input: &String) -> AoCResult {
-    let mut prgm = IntcodeProgram::new(input, None);
+pub fn solve_part2(prgm: &IntcodeProgram) -> Result<AoCResult, AoCError> {
+    let mut prgm = prgm.clone();
     
     // Jump, if one of the three tiles before the robot contain a hole and the tiles at distance 4 and distance 8 are solid ground
     // Also, always jump if the tile directly before the robot is a hole
@@ -56,9 +60,9 @@ pub fn solve_part2(input: &String) -> AoCResult {
             .map(|x| (*x as u8) as char)
             .collect::<String>();
 
-        println!("{}", camera_line);
+        aoc_core::trace!("{}", camera_line);
         camera_view.push(camera_line);
     }
 
-    AoCResult::Num(*prgm.output.last().unwrap() as u64)
+    Ok(AoCResult::Int(*prgm.output.last().ok_or(AoCError::NoSolution)?))
 }
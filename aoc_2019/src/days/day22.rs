@@ -1,25 +1,19 @@
-use aoc_core::AoCResult;
-
-aoc_core::make_day!(Day22);
-
-pub fn solve_part1(input: &String) -> AoCResult {
-    let shufflings = input
-        .trim_end()
-        .lines()
-        .map(|l| {
-            if l == "deal into new stack" {
-                // new stack = reverse deck
-                ShuffleTechnique::DealNewStack
-            } else if let Some(cut_val) = l.strip_prefix("cut ") {
-                // cut
-                ShuffleTechnique::Cut(cut_val.parse().unwrap())
-            } else {
-                // deal with increment
-                ShuffleTechnique::DealWithIncrement(l.strip_prefix("deal with increment ").unwrap().parse().unwrap())
-            }
-        });
-
-    let mut deck: Vec<u32> = (0..10007).collect();
+use aoc_core::{AoCError, AoCResult, Params};
+use aoc_core::aoc_error::{column_of, parse_at};
+
+aoc_core::make_day!(Day22, 2019, 22, params: [
+    deck_size = "10007",
+    card = "2019",
+    large_deck_size = "119315717514047",
+    shuffles = "101741582076661",
+    position = "2020",
+]);
+
+pub fn solve_part1(input: &str, params: &Params) -> Result<AoCResult, AoCError> {
+    let shufflings = get_shufflings(input)?;
+    let card = params.get::<u32>("card")?;
+
+    let mut deck: Vec<u32> = (0..params.get_positive("deck_size")?).collect();
     
     for s in shufflings {
         match s {
@@ -42,28 +36,15 @@ pub fn solve_part1(input: &String) -> AoCResult {
         }
     }
 
-    let card2019_index = deck.into_iter().enumerate().find(|(_, c)| c == &2019).unwrap().0;
-    AoCResult::Num(card2019_index as u64)
+    let card_index = deck.into_iter().position(|c| c == card).ok_or(AoCError::NoSolution)?;
+    Ok(AoCResult::from(card_index))
 }
 
-pub fn solve_part2(input: &String) -> AoCResult {
-    let shufflings = input
-        .trim_end()
-        .lines()
-        .map(|l| {
-            if l == "deal into new stack" {
-                // new stack = reverse deck
-                ShuffleTechnique::DealNewStack
-            } else if let Some(cut_val) = l.strip_prefix("cut ") {
-                // cut
-                ShuffleTechnique::Cut(cut_val.parse().unwrap())
-            } else {
-                // deal with increment
-                ShuffleTechnique::DealWithIncrement(l.strip_prefix("deal with increment ").unwrap().parse().unwrap())
-            }
-        });
+pub fn solve_part2(input: &str, params: &Params) -> Result<AoCResult, AoCError> {
+    let shufflings = get_shufflings(input)?;
 
-    let deck_len: i128 = 119315717514047;
+    // Deck size must be prime for the modular inverse below
+    let deck_len: i128 = params.get_positive("large_deck_size")?;
 
     // Represent deck as pair of offset and increment:
     // -> solution taken from: https://www.reddit.com/r/adventofcode/comments/ee0rqi/comment/fbnkaju/
@@ -122,7 +103,7 @@ pub fn solve_part2(input: &String) -> AoCResult {
 
     // After one shuffle, the values for offset and increment are:
     let (offset_diff, incr_diff) = (offset % deck_len, increment % deck_len);
-    println!("Offset: {}, incr.: {}", offset_diff, incr_diff);
+    aoc_core::debug!("Offset: {}, incr.: {}", offset_diff, incr_diff);
 
     // After each shuffle, both values with change by:
     // - increment is only increased by multiplication with a constant number (the factor above depends neither on the old increment nor the offset)
@@ -135,7 +116,7 @@ pub fn solve_part2(input: &String) -> AoCResult {
     //   -> Initial increment is 1 so c = offset_diff
     
     // After N shuffles:
-    let shuffles: i128 = 101741582076661;
+    let shuffles: i128 = params.get_positive("shuffles")?;
 
     // Increment will be: increment_new = incr_diff ^ N
     let final_increment = mod_pow(incr_diff, shuffles, deck_len);
@@ -166,10 +147,37 @@ pub fn solve_part2(input: &String) -> AoCResult {
     let b = factor_2;
     let final_offset = ((a % deck_len) * (b % deck_len)) % deck_len;
 
-    // Card at position 2020:
-    let res = (final_increment * 2020 + final_offset) % deck_len;
+    // Card at the requested position:
+    let position: i128 = params.get("position")?;
+    if !(0..deck_len).contains(&position) {
+        return Err(AoCError::InvalidParameter(format!("position={}: must be less than large_deck_size", position)));
+    }
+    let res = (final_increment * position + final_offset).rem_euclid(deck_len);
+
+    Ok(AoCResult::Wide(res))
+}
 
-    AoCResult::Num(res as u64)
+fn get_shufflings(input: &str) -> Result<Vec<ShuffleTechnique>, AoCError> {
+    input
+        .trim_end()
+        .lines()
+        .enumerate()
+        .map(|(i, l)| {
+            let l = l.trim_end();
+            if l == "deal into new stack" {
+                // new stack = reverse deck
+                Ok(ShuffleTechnique::DealNewStack)
+            } else if let Some(cut_val) = l.strip_prefix("cut ") {
+                // cut
+                Ok(ShuffleTechnique::Cut(parse_at(cut_val, i + 1, column_of(l, cut_val))?))
+            } else if let Some(inc_val) = l.strip_prefix("deal with increment ") {
+                // deal with increment
+                Ok(ShuffleTechnique::DealWithIncrement(parse_at(inc_val, i + 1, column_of(l, inc_val))?))
+            } else {
+                Err(AoCError::parse_error(i + 1, 1, format!("unknown shuffle technique '{}'", l)))
+            }
+        })
+        .collect()
 }
 
 enum ShuffleTechnique {
@@ -192,5 +200,36 @@ fn mod_pow(mut base: i128, mut exp: i128, modulo: i128) -> i128 {
         exp >>= 1;
         base = (base * base) % modulo;
     }
-    return result;
-}
\ No newline at end of file
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::{IoContext, PuzzleInput};
+
+    #[test]
+    fn part1_example1() {
+        // Resulting deck: 0 3 6 9 2 5 8 1 4 7
+        let input = PuzzleInput::from("deal with increment 7\r\ndeal into new stack\r\ndeal into new stack");
+        let result = Day22 {}.run_part1(&input, &Params::from([("deck_size", "10"), ("card", "9")]), &mut IoContext::silent()).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 3);
+    }
+
+    #[test]
+    fn part2_reverses_part1() {
+        // After a single shuffle, part 2 has to find the card whose position part 1 determined
+        let input = PuzzleInput::from("cut 6\r\ndeal with increment 7\r\ndeal into new stack");
+        let result = Day22 {}.run_part1(&input, &Params::new(), &mut IoContext::silent()).unwrap();
+        let params = Params::from([("large_deck_size", "10007"), ("shuffles", "1")]).with("position", result);
+        let result = Day22 {}.run_part2(&input, &params, &mut IoContext::silent()).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 2019);
+    }
+
+    #[test]
+    fn empty_deck() {
+        let input = PuzzleInput::from("deal with increment 7");
+        let result = Day22 {}.run_part1(&input, &Params::from([("deck_size", "0")]), &mut IoContext::silent());
+        assert!(matches!(result, Err(AoCError::InvalidParameter(_))));
+    }
+}
@@ -1,11 +1,14 @@
-use aoc_core::AoCResult;
+use aoc_core::{AoCError, AoCResult, PuzzleInput};
 use crate::shared::intcode::{InstructionResult, IntcodeProgram, RunMode};
 use std::collections::VecDeque;
 
-aoc_core::make_day!(Day23);
+aoc_core::make_day!(Day23, 2019, 23, parsed: IntcodeProgram);
 
-pub fn solve_part1(input: &String) -> AoCResult {
-    let prgm = IntcodeProgram::new(input, None);
+pub fn parse(input: &PuzzleInput) -> Result<IntcodeProgram, AoCError> {
+    IntcodeProgram::new(input, None)
+}
+
+pub fn solve_part1(prgm: &IntcodeProgram) -> Result<AoCResult, AoCError> {
     let mut network = vec![NetworkComputer::new(prgm.clone()); 50];
 
     // Assign network addresses:
@@ -20,8 +23,8 @@ pub fn solve_part1(input: &String) -> AoCResult {
         let mut packets = Vec::<Packet>::new();
 
         // Process next instruction in each network computer:
-        for next_computer in 0..50 {
-            let next_packet = network[next_computer].run();
+        for net_comp in network.iter_mut() {
+            let next_packet = net_comp.run();
             if let Some(packet) = next_packet {
                 if packet.address == 255 {
                     result = packet.Y;
@@ -32,17 +35,23 @@ pub fn solve_part1(input: &String) -> AoCResult {
             }
         }
 
-        // Cycle complete: transmit packets
+        // Cycle complete: transmit packets (packets to unknown addresses are lost)
         for p in packets {
-            network[p.address].receive_packet(p);
+            if let Some(receiver) = network.get_mut(p.address) {
+                receiver.receive_packet(p);
+            }
+        }
+
+        // Nothing is sent anymore if all computers wait for packets or have stopped:
+        if network.iter().all(|c| c.is_idle() || c.is_stopped()) {
+            return Err(AoCError::NoSolution);
         }
     }
 
-    AoCResult::Num(result as u64)
+    Ok(AoCResult::Int(result))
 }
 
-pub fn solve_part2(input: &String) -> AoCResult {
-    let prgm = IntcodeProgram::new(input, None);
+pub fn solve_part2(prgm: &IntcodeProgram) -> Result<AoCResult, AoCError> {
     let mut network = vec![NetworkComputer::new(prgm.clone()); 50];
 
     // Assign network addresses:
@@ -60,8 +69,8 @@ pub fn solve_part2(input: &String) -> AoCResult {
         let mut packets = Vec::<Packet>::new();
 
         // Process next instruction in each network computer:
-        for next_computer in 0..50 {
-            let next_packet = network[next_computer].run();
+        for net_comp in network.iter_mut() {
+            let next_packet = net_comp.run();
             if let Some(packet) = next_packet {
                 if packet.address == 255 {
                     nat_packet = Some(packet);
@@ -71,31 +80,37 @@ pub fn solve_part2(input: &String) -> AoCResult {
             }
         }
 
-        // Cycle complete: transmit packets
+        // Cycle complete: transmit packets (packets to unknown addresses are lost)
         for p in packets {
-            network[p.address].receive_packet(p);
+            if let Some(receiver) = network.get_mut(p.address) {
+                receiver.receive_packet(p);
+            }
         }
 
         // Check if all computers in the network are idle:
-        if let Some(nat_payload) = &nat_packet {
-            if network.iter().all(|c| c.is_idle()) {
-                // Was this value already sent?
-                if last_nat_transmission == nat_payload.Y {
-                    break 'network_loop;
-                } else {
-                    // Send NAT packet to computer 0:
-                    last_nat_transmission = nat_payload.Y;
-                    network[0].receive_packet(nat_payload.clone());
-                }
-            }   
+        if network.iter().all(|c| c.is_idle() || c.is_stopped()) {
+            // Without a NAT packet, the network cannot be woken up:
+            let Some(nat_payload) = &nat_packet else {
+                return Err(AoCError::NoSolution);
+            };
+
+            // Was this value already sent?
+            if last_nat_transmission == nat_payload.Y {
+                break 'network_loop;
+            } else {
+                // Send NAT packet to computer 0:
+                last_nat_transmission = nat_payload.Y;
+                network[0].receive_packet(nat_payload.clone());
+            }
         }
     }
 
-    AoCResult::Num(last_nat_transmission as u64)
+    Ok(AoCResult::Int(last_nat_transmission))
 }
 
 
 #[derive(Clone, PartialEq)]
+#[allow(clippy::upper_case_acronyms)]
 enum NetworkState {
     ACTIVE,     // normal state while receiving and sending packets
     PASSIVE,    // has not received packets, but may send packets themselves
@@ -114,7 +129,7 @@ struct NetworkComputer {
 impl NetworkComputer {
     pub fn new(nic: IntcodeProgram) -> Self {
         Self {
-            nic: nic,
+            nic,
             packet_queue: VecDeque::new(),
             address: 0,
             last_state: InstructionResult::HALT,
@@ -129,6 +144,10 @@ impl NetworkComputer {
     }
 
     pub fn run(&mut self) -> Option<Packet> {
+        if self.is_stopped() {
+            return None;
+        }
+
         if self.last_state == InstructionResult::WAIT_FOR_INPUT {
             // Dequeue packet if available:
             if let Some(packet) = self.packet_queue.pop_front() {
@@ -164,13 +183,18 @@ impl NetworkComputer {
             return Some(packet);
         }
 
-        return None;
+        None
     }
 
     pub fn receive_packet(&mut self, p: Packet) {
         self.packet_queue.push_back(p);
     }
 
+    // Halted or crashed computers do not run anymore
+    pub fn is_stopped(&self) -> bool {
+        !matches!(self.last_state, InstructionResult::RUNNING | InstructionResult::WAIT_FOR_INPUT)
+    }
+
     // Computer must read input twice without sending packets in between to be considered idle:
     pub fn is_idle(&self) -> bool {
         self.packet_queue.is_empty() && self.network_state == NetworkState::IDLE
@@ -183,4 +207,23 @@ struct Packet {
     address: usize,
     X: i64,
     Y: i64
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_halting_network() {
+        let input = PuzzleInput::from("3,100,99");
+        let result = solve_part1(&parse(&input).unwrap());
+        assert!(matches!(result, Err(AoCError::NoSolution)));
+    }
+
+    #[test]
+    fn part2_idle_network() {
+        // Computers that keep reading without ever sending packets
+        let input = PuzzleInput::from("3,100,1105,1,0");
+        let result = solve_part2(&parse(&input).unwrap());
+        assert!(matches!(result, Err(AoCError::NoSolution)));
+    }
+}
@@ -1,15 +1,11 @@
-use aoc_core::AoCResult;
+use aoc_core::{AoCError, AoCResult};
 use std::collections::{HashMap, HashSet};
 use itertools::Itertools;
 
-aoc_core::make_day!(Day24);
+aoc_core::make_day!(Day24, 2019, 24);
 
-pub fn solve_part1(input: &String) -> AoCResult {
-    let mut area = input
-        .trim_end()
-        .lines()
-        .map(str::to_owned)
-        .collect_vec();
+pub fn solve_part1(input: &str) -> Result<AoCResult, AoCError> {
+    let mut area = parse_area(input)?;
 
     let mut prev_layouts = HashSet::<String>::new();
 
@@ -30,12 +26,12 @@ pub fn solve_part1(input: &String) -> AoCResult {
         area = process_minute(area);
     }
 
-    AoCResult::Num(result)
+    Ok(AoCResult::Num(result))
 }
 
-pub fn solve_part2(input: &String) -> AoCResult {
+pub fn solve_part2(input: &str) -> Result<AoCResult, AoCError> {
     let mut bugs = HashSet::<RecursiveCell>::new();
-    for (y, line) in input.trim_end().lines().enumerate() {
+    for (y, line) in parse_area(input)?.iter().enumerate() {
         for (x, c) in line.chars().enumerate() {
             if c == '#' {
                 bugs.insert(RecursiveCell { x: x as i32, y: y as i32, level: 0 });
@@ -47,7 +43,26 @@ pub fn solve_part2(input: &String) -> AoCResult {
         bugs = process_minute_recursive(bugs);
     }
 
-    AoCResult::Num(bugs.len() as u64)
+    Ok(AoCResult::Num(bugs.len() as u64))
+}
+
+// The area is a 5x5 grid of bugs ('#') and empty tiles ('.')
+fn parse_area(input: &str) -> Result<Vec<String>, AoCError> {
+    let area = input.trim_end().lines().map(str::to_owned).collect_vec();
+    for (y, line) in area.iter().enumerate() {
+        if y >= 5 {
+            return Err(AoCError::parse_error(y + 1, 1, "expected 5 rows"));
+        } else if let Some(x) = line.chars().position(|c| c != '#' && c != '.') {
+            return Err(AoCError::parse_error(y + 1, x + 1, "expected '#' or '.'"));
+        } else if line.len() != 5 {
+            return Err(AoCError::parse_error(y + 1, 1, format!("expected 5 columns, found {}", line.len())));
+        }
+    }
+
+    if area.len() < 5 {
+        return Err(AoCError::parse_error(area.len() + 1, 1, "expected 5 rows"));
+    }
+    Ok(area)
 }
 
 fn process_minute(area: Vec<String>) -> Vec<String> {
@@ -88,7 +103,7 @@ fn process_minute(area: Vec<String>) -> Vec<String> {
         next_area.push(next_line);
     }
 
-    return next_area;
+    next_area
 }
 
 fn process_minute_recursive(bugs: HashSet<RecursiveCell>) -> HashSet<RecursiveCell> {
@@ -99,7 +114,7 @@ fn process_minute_recursive(bugs: HashSet<RecursiveCell>) -> HashSet<RecursiveCe
 
         // Check neighbor cells:
         let mut check_neighbor = |x: i32, y: i32, level: i32| {
-            let neighbor = RecursiveCell { x: x, y: y, level: level };
+            let neighbor = RecursiveCell { x, y, level };
             if bugs.iter().contains(&neighbor) { 
                 adjacent_bugs += 1;
             } else { 
@@ -191,8 +206,8 @@ mod tests {
     #[test]
     fn part1_example1() {
         let input = String::from("....#\r\n#..#.\r\n#..##\r\n..#..\r\n#....");
-        let result = solve_part1(&input);
-        assert_eq!(u64::from(result), 2129920);
+        let result = solve_part1(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 2129920);
     }
 
     #[test]
@@ -214,4 +229,11 @@ mod tests {
         
         assert_eq!(bugs.len(), 99);
     }
+
+    #[test]
+    fn part1_invalid_input() {
+        let input = String::from("....#\r\n#..#.\r\n#..##\r\n..#..");
+        let result = solve_part1(&input);
+        assert!(matches!(result, Err(AoCError::ParseError { line: 5, column: 1, .. })));
+    }
 }
@@ -1,11 +1,14 @@
-use aoc_core::AoCResult;
+use aoc_core::{AoCError, AoCResult, IoContext, PuzzleInput};
 use crate::shared::intcode::{InstructionResult, IntcodeProgram, RunMode};
-use std::io;
 
-aoc_core::make_day!(Day25);
+aoc_core::make_day!(Day25, 2019, 25, parsed: IntcodeProgram, interactive, io);
 
-pub fn solve_part1(input: &String) -> AoCResult {
-    let mut prgm = IntcodeProgram::new(input, None);
+pub fn parse(input: &PuzzleInput) -> Result<IntcodeProgram, AoCError> {
+    IntcodeProgram::new(input, None)
+}
+
+pub fn solve_part1(prgm: &IntcodeProgram, io: &mut IoContext) -> Result<AoCResult, AoCError> {
+    let mut prgm = prgm.clone();
     let mut state = prgm.run(RunMode::Step);
 
 
@@ -33,29 +36,75 @@ pub fn solve_part1(input: &String) -> AoCResult {
     //                      | Arcade |-----| Corridor |
     //                      +--------+     +----------+
 
+    // Everything the droid reported, the password is contained in its last message
+    let mut transcript = String::new();
+
     loop {
         match state {
             InstructionResult::HALT => { break; }
             InstructionResult::WAIT_FOR_INPUT => {
-                let mut input = String::new();
-                if io::stdin().read_line(&mut input).is_ok() {
-                    prgm.input_ascii(input.replace("\r", "").as_str());
-                }
+                // Without further commands, the droid cannot get any further:
+                let command = io.read_line().ok_or(AoCError::NoSolution)?;
+                prgm.input_ascii(&format!("{}\n", command));
             }
-            InstructionResult::RUNNING => {
-                // Check for output characters:
-                if !prgm.output.is_empty() {
-                    print!("{}", (prgm.output[0] as u8) as char);
-                    prgm.output.clear();
-                }
+            // Check for output characters:
+            InstructionResult::RUNNING if !prgm.output.is_empty() => {
+                let c = (prgm.output[0] as u8) as char;
+                io.write(&c.to_string());
+                transcript.push(c);
+                prgm.output.clear();
             }
-            _ => { }
+            InstructionResult::RUNNING => { }
+            // The droid crashed:
+            _ => { return Err(AoCError::NoSolution); }
         };
 
         state = prgm.run_step();
     }
 
-    AoCResult::PrintedToConsole
+    // "... You should be able to get in by typing 12345 on the keypad at the main airlock."
+    let password = transcript
+        .split("typing ")
+        .last()
+        .and_then(|s| s.split_whitespace().next())
+        .and_then(|s| s.parse::<u64>().ok());
+
+    Ok(password.map_or(AoCResult::PrintedToConsole, AoCResult::Num))
 }
 
-pub fn solve_part2(_input: &String) -> AoCResult { AoCResult::NotImplemented }
\ No newline at end of file
+pub fn solve_part2(_prgm: &IntcodeProgram, _io: &mut IoContext) -> Result<AoCResult, AoCError> { Ok(AoCResult::NotImplemented) }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Echoes the first line of input back as ASCII output, then halts
+    const ECHO_PRGM: &str = "3,100,4,100,1008,100,10,101,1005,101,14,1105,1,0,99";
+
+    fn droid(prgm: &str) -> IntcodeProgram {
+        parse(&PuzzleInput::from(prgm)).unwrap()
+    }
+
+    #[test]
+    fn part1_scripted() {
+        let mut output = vec![];
+        let mut io = IoContext::new("typing 8462464 on the keypad\r\n".as_bytes(), &mut output);
+        let result = solve_part1(&droid(ECHO_PRGM), &mut io).unwrap();
+        drop(io);
+        assert_eq!(u64::try_from(result).unwrap(), 8462464);
+        assert_eq!(String::from_utf8(output).unwrap(), "typing 8462464 on the keypad\n");
+    }
+
+    #[test]
+    fn part1_script_exhausted() {
+        let mut io = IoContext::new("".as_bytes(), std::io::sink());
+        assert!(matches!(solve_part1(&droid(ECHO_PRGM), &mut io), Err(AoCError::NoSolution)));
+    }
+
+    #[test]
+    fn part1_crashed_droid() {
+        // Program that ends on an unknown instruction instead of halting
+        let mut io = IoContext::new("".as_bytes(), std::io::sink());
+        assert!(matches!(solve_part1(&droid("104,35,42"), &mut io), Err(AoCError::NoSolution)));
+    }
+}
\ No newline at end of file
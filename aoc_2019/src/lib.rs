@@ -1,50 +1,22 @@
 pub mod days;
-use aoc_core::AoCResult;
-use aoc_core::AoCDay;
-
-use crate::days::*;
 
 pub mod shared;
 
-pub fn solve(day: u8, part: u8, input: &String) -> AoCResult {
-    let solver: Option<&dyn AoCDay> = match day {
-         1 => Some(&day01::Day01 {}),
-         2 => Some(&day02::Day02 {}),
-         3 => Some(&day03::Day03 {}),
-         4 => Some(&day04::Day04 {}),
-         5 => Some(&day05::Day05 {}),
-         6 => Some(&day06::Day06 {}),
-         7 => Some(&day07::Day07 {}),
-         8 => Some(&day08::Day08 {}),
-         9 => Some(&day09::Day09 {}),
-        10 => Some(&day10::Day10 {}),
-        11 => Some(&day11::Day11 {}),
-        12 => Some(&day12::Day12 {}),
-        13 => Some(&day13::Day13 {}),
-        14 => Some(&day14::Day14 {}),
-        15 => Some(&day15::Day15 {}),
-        16 => Some(&day16::Day16 {}),
-        17 => Some(&day17::Day17 {}),
-        18 => Some(&day18::Day18 {}),
-        19 => Some(&day19::Day19 {}),
-        20 => Some(&day20::Day20 {}),
-        21 => Some(&day21::Day21 {}),
-        22 => Some(&day22::Day22 {}),
-        23 => Some(&day23::Day23 {}),
-        24 => Some(&day24::Day24 {}),
-        25 => Some(&day25::Day25 {}),
-         _ => None,
-    };
+aoc_core::make_year!(Year2019, 2019);
+
+// One test per file in examples/2019, see build.rs
+#[cfg(test)]
+mod examples {
+    include!(concat!(env!("OUT_DIR"), "/example_tests.rs"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::AoCYear;
 
-    if let Some(solver) = solver {
-        if part == 1 {
-            solver.run_part1(input)
-        } else if part == 2 {
-            solver.run_part2(input)
-        } else {
-            AoCResult::InvalidPartErr(day, part)
-        }
-    } else {
-        AoCResult::InvalidDayErr(day)
+    #[test]
+    fn all_days_registered() {
+        assert_eq!(Year2019 {}.days(), (1..=25).collect::<Vec<u8>>());
     }
 }
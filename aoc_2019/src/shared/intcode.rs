@@ -1,7 +1,5 @@
-use std::{
-    collections::{HashMap, VecDeque},
-    str::FromStr,
-};
+use aoc_core::{AoCError, PuzzleInput};
+use std::collections::{HashMap, VecDeque};
 
 #[derive(Clone)]
 pub struct IntcodeProgram {
@@ -16,7 +14,7 @@ pub struct IntcodeProgram {
 }
 
 #[derive(PartialEq)]
-#[allow(non_camel_case_types)]
+#[allow(non_camel_case_types, clippy::upper_case_acronyms)]
 enum OpCodeType {
     HALT,
     ADD,           // 01
@@ -89,13 +87,15 @@ impl Instruction {
 }
 
 #[derive(Clone, PartialEq)]
-#[allow(non_camel_case_types)]
+#[allow(non_camel_case_types, clippy::upper_case_acronyms)]
 pub enum InstructionResult {
     RUNNING,
     WAIT_FOR_INPUT,
     HALT,
     EOF,
     UNKNOWN_OP_CODE,
+    // Negative address or overflowing value
+    INVALID_ARGUMENT,
 }
 
 pub enum RunMode {
@@ -105,19 +105,20 @@ pub enum RunMode {
 
 impl IntcodeProgram {
     // Creates a new intcode program
-    pub fn new(code: &String, input: Option<VecDeque<i64>>) -> Self {
-        Self {
-            prgm: code
-                .trim_end()
-                .split(',')
-                .map(|s| i64::from_str(s).unwrap())
-                .collect(),
+    pub fn new(code: &str, input: Option<VecDeque<i64>>) -> Result<Self, AoCError> {
+        let prgm = PuzzleInput::new(code).csv_ints()?;
+        if prgm.is_empty() {
+            return Err(AoCError::parse_error(1, 1, "empty Intcode program"));
+        }
+
+        Ok(Self {
+            prgm,
             instr_ptr: 0,
             relative_base_offset: 0,
-            input: input.unwrap_or(VecDeque::new()),
+            input: input.unwrap_or_default(),
             output: vec![],
             extended_memory: HashMap::new(),
-        }
+        })
     }
 
     // Reads the current value inside the intcode program at the given position
@@ -160,20 +161,20 @@ impl IntcodeProgram {
         while last_state == InstructionResult::RUNNING {
             last_state = self.run_step();
         };
-        return last_state;
+        last_state
     }
 
     // Run a single instruction
     pub fn run_step(&mut self) -> InstructionResult {
         if let Some(instr) = self.next_instruction() {
-            let next_state: InstructionResult = self.apply_instruction(&instr);
+            let next_state = self.apply_instruction(&instr).unwrap_or(InstructionResult::INVALID_ARGUMENT);
 
             if next_state == InstructionResult::WAIT_FOR_INPUT {
                 // Reset instruction pointer so current instruction can be run again when program is continued:
                 self.instr_ptr -= instr.get_arg_count() + 1;
             }
 
-            return next_state;
+            next_state
         } else {
             InstructionResult::EOF
         }
@@ -187,9 +188,9 @@ impl IntcodeProgram {
         if mode == ParameterMode::Immediate {
             Some(val)
         } else if mode == ParameterMode::Positional {
-            Some(self.read(usize::try_from(val).unwrap()))
+            Some(self.read(usize::try_from(val).ok()?))
         } else if mode == ParameterMode::Relative {
-            Some(self.read(usize::try_from(val + self.relative_base_offset).unwrap()))
+            Some(self.read(usize::try_from(val.checked_add(self.relative_base_offset)?).ok()?))
         } else {
             None
         }
@@ -209,7 +210,7 @@ impl IntcodeProgram {
                 // adress given is always read as immediate value, but the resulting position depends on parameter mode
                 let address = self.next_value(ParameterMode::Immediate);
                 if let Some(addr_val) = address && param_mode == ParameterMode::Relative {
-                    Some(addr_val + self.relative_base_offset)
+                    addr_val.checked_add(self.relative_base_offset)
                 } else {
                     address
                 }
@@ -218,7 +219,7 @@ impl IntcodeProgram {
                 // adress given is always read as immediate value, but the resulting position depends on parameter mode
                 let address = self.next_value(ParameterMode::Immediate);
                 if let Some(addr_val) = address && param_mode == ParameterMode::Relative {
-                    Some(addr_val + self.relative_base_offset)
+                    addr_val.checked_add(self.relative_base_offset)
                 } else {
                     address
                 }
@@ -256,68 +257,73 @@ impl IntcodeProgram {
         }
     }
 
-    // Applies the given instruction to the intcode program
-    fn apply_instruction(&mut self, instr: &Instruction) -> InstructionResult {
+    // Applies the given instruction to the intcode program (None if an argument is invalid)
+    fn apply_instruction(&mut self, instr: &Instruction) -> Option<InstructionResult> {
         match instr.opcode {
-            OpCodeType::HALT => InstructionResult::HALT,
+            OpCodeType::HALT => Some(InstructionResult::HALT),
             OpCodeType::ADD => {
                 self.write(
-                    usize::try_from(instr.arg3.unwrap()).unwrap(),
-                    instr.arg1.unwrap() + instr.arg2.unwrap(),
+                    address(instr.arg3)?,
+                    instr.arg1?.checked_add(instr.arg2?)?,
                 );
-                InstructionResult::RUNNING
+                Some(InstructionResult::RUNNING)
             }
             OpCodeType::MULT => {
                 self.write(
-                    usize::try_from(instr.arg3.unwrap()).unwrap(),
-                    instr.arg1.unwrap() * instr.arg2.unwrap(),
+                    address(instr.arg3)?,
+                    instr.arg1?.checked_mul(instr.arg2?)?,
                 );
-                InstructionResult::RUNNING
+                Some(InstructionResult::RUNNING)
             }
             OpCodeType::READ => {
                 let input_val = self.input.pop_front();
                 if let Some(val) = input_val {
-                    self.write(usize::try_from(instr.arg1.unwrap()).unwrap(), val);
-                    InstructionResult::RUNNING
+                    self.write(address(instr.arg1)?, val);
+                    Some(InstructionResult::RUNNING)
                 } else {
-                    InstructionResult::WAIT_FOR_INPUT
+                    Some(InstructionResult::WAIT_FOR_INPUT)
                 }
             }
             OpCodeType::WRITE => {
-                self.output.push(instr.arg1.unwrap());
-                InstructionResult::RUNNING
+                self.output.push(instr.arg1?);
+                Some(InstructionResult::RUNNING)
             }
             OpCodeType::JUMP_IF_TRUE => {
-                if instr.arg1.unwrap() != 0 {
-                    self.instr_ptr = usize::try_from(instr.arg2.unwrap()).unwrap();
+                if instr.arg1? != 0 {
+                    self.instr_ptr = address(instr.arg2)?;
                 }
-                InstructionResult::RUNNING
+                Some(InstructionResult::RUNNING)
             }
             OpCodeType::JUMP_IF_FALSE => {
-                if instr.arg1.unwrap() == 0 {
-                    self.instr_ptr = usize::try_from(instr.arg2.unwrap()).unwrap();
+                if instr.arg1? == 0 {
+                    self.instr_ptr = address(instr.arg2)?;
                 }
-                InstructionResult::RUNNING
+                Some(InstructionResult::RUNNING)
             }
             OpCodeType::LESS_THAN => {
-				let output = if instr.arg1.unwrap() < instr.arg2.unwrap() { 1 } else { 0 };
-                self.write(usize::try_from(instr.arg3.unwrap()).unwrap(), output);
-                InstructionResult::RUNNING
+				let output = if instr.arg1? < instr.arg2? { 1 } else { 0 };
+                self.write(address(instr.arg3)?, output);
+                Some(InstructionResult::RUNNING)
             }
             OpCodeType::EQUALS => {
-				let output = if instr.arg1.unwrap() == instr.arg2.unwrap() { 1 } else { 0 };
-                self.write(usize::try_from(instr.arg3.unwrap()).unwrap(), output);
-                InstructionResult::RUNNING
+				let output = if instr.arg1? == instr.arg2? { 1 } else { 0 };
+                self.write(address(instr.arg3)?, output);
+                Some(InstructionResult::RUNNING)
             }
             OpCodeType::MOVE_REL_OFFS => {
-                self.relative_base_offset += instr.arg1.unwrap();
-                InstructionResult::RUNNING
+                self.relative_base_offset = self.relative_base_offset.checked_add(instr.arg1?)?;
+                Some(InstructionResult::RUNNING)
             }
-            _ => InstructionResult::UNKNOWN_OP_CODE,
+            _ => Some(InstructionResult::UNKNOWN_OP_CODE),
         }
     }
 }
 
+// Address given by an instruction argument
+fn address(arg: Option<i64>) -> Option<usize> {
+    usize::try_from(arg?).ok()
+}
+
 impl From<Vec<i64>> for IntcodeProgram {
     fn from(value: Vec<i64>) -> Self {
         Self {
@@ -0,0 +1,4 @@
+// Generates a test for every example in examples/2025, see aoc_core::examples
+fn main() {
+    aoc_core::examples::generate_tests(2025, "../examples/2025");
+}
@@ -1,10 +1,10 @@
-use aoc_core::AoCResult;
-use itertools::Itertools;
+use aoc_core::{AoCError, AoCResult};
+use aoc_core::aoc_error::parse_at;
 
-aoc_core::make_day!(Day01);
+aoc_core::make_day!(Day01, 2025, 1);
 
-pub fn solve_part1(input: &String) -> AoCResult {
-    let rotations = get_rotations(input);
+pub fn solve_part1(input: &str) -> Result<AoCResult, AoCError> {
+    let rotations = get_rotations(input)?;
 
     let mut dial = 50;
     let mut zero_passes = 0;
@@ -17,18 +17,18 @@ pub fn solve_part1(input: &String) -> AoCResult {
         }
     }
 
-    AoCResult::Num(zero_passes)
+    Ok(AoCResult::Num(zero_passes))
 }
 
-pub fn solve_part2(input: &String) -> AoCResult {
-    let rotations = get_rotations(input);
+pub fn solve_part2(input: &str) -> Result<AoCResult, AoCError> {
+    let rotations = get_rotations(input)?;
 
     let mut dial = 50;
     let mut zero_passes = 0;
 
     for rot in rotations {
         // Count number of times the dial passes through zero during rotation:
-        let full_rotations = (rot / 100).abs() as u64;
+        let full_rotations = (rot / 100).unsigned_abs();
         zero_passes += full_rotations;
         
         // Remaining rotatation after performing all full rotations:
@@ -54,21 +54,23 @@ pub fn solve_part2(input: &String) -> AoCResult {
         }
     }
 
-    AoCResult::Num(zero_passes)
+    Ok(AoCResult::Num(zero_passes))
 }
 
-fn get_rotations(input: &String) -> Vec<i64> {
+fn get_rotations(input: &str) -> Result<Vec<i64>, AoCError> {
     input
         .trim_end()
         .lines()
-        .map(|l| {
-            match l.chars().nth(0).unwrap() {
-                'L' => l.strip_prefix("L").unwrap().parse::<i64>().unwrap() * -1,
-                'R' => l.strip_prefix("R").unwrap().parse().unwrap(),
-                _ => 0i64
+        .enumerate()
+        .map(|(i, l)| {
+            let l = l.trim_end();
+            match l.chars().next() {
+                Some('L') => parse_at::<i64>(&l[1..], i + 1, 2).map(|n| -n),
+                Some('R') => parse_at(&l[1..], i + 1, 2),
+                _ => Err(AoCError::parse_error(i + 1, 1, "expected rotation 'L' or 'R'")),
             }
         })
-        .collect_vec()
+        .collect()
 }
 
 
@@ -79,14 +81,14 @@ mod tests {
     #[test]
     fn part1_example1() {
         let input = String::from("L68\r\nL30\r\nR48\r\nL5\r\nR60\r\nL55\r\nL1\r\nL99\r\nR14\r\nL82");
-        let result = solve_part1(&input);
-        assert_eq!(u64::from(result), 3);
+        let result = solve_part1(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 3);
     }
 
     #[test]
     fn part2_example1() {
         let input = String::from("L68\r\nL30\r\nR48\r\nL5\r\nR60\r\nL55\r\nL1\r\nL99\r\nR14\r\nL82");
-        let result = solve_part2(&input);
-        assert_eq!(u64::from(result), 6);
+        let result = solve_part2(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 6);
     }
 }
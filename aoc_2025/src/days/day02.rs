@@ -1,11 +1,11 @@
-use aoc_core::AoCResult;
+use aoc_core::{AoCError, AoCResult};
+use aoc_core::aoc_error::{column_of, parse_at};
 use std::collections::HashSet;
-use itertools::Itertools;
 
-aoc_core::make_day!(Day02);
+aoc_core::make_day!(Day02, 2025, 2);
 
-pub fn solve_part1(input: &String) -> AoCResult {
-    let ranges = get_ranges(input);
+pub fn solve_part1(input: &str) -> Result<AoCResult, AoCError> {
+    let ranges = get_ranges(input)?;
 
     let mut invalid_id_sum = 0;
     for (start, end) in ranges {
@@ -32,11 +32,11 @@ pub fn solve_part1(input: &String) -> AoCResult {
         }
     }
 
-    AoCResult::Num(invalid_id_sum)
+    Ok(AoCResult::Num(invalid_id_sum))
 }
 
-pub fn solve_part2(input: &String) -> AoCResult {
-    let ranges = get_ranges(input);
+pub fn solve_part2(input: &str) -> Result<AoCResult, AoCError> {
+    let ranges = get_ranges(input)?;
 
     // Because of the way patterns are checked, the same pattern can occur multiple times
     // Collect in hashset, then sum up at the end (assumes the ranges do not overlap,
@@ -73,15 +73,24 @@ pub fn solve_part2(input: &String) -> AoCResult {
     }
 
     let invalid_id_sum = invalid_ids.iter().sum();
-    AoCResult::Num(invalid_id_sum)
+    Ok(AoCResult::Num(invalid_id_sum))
 }
 
-fn get_ranges(input: &String) -> Vec<(&str, &str)> {
+fn get_ranges(input: &str) -> Result<Vec<(&str, &str)>, AoCError> {
     input
         .trim_end()
         .split(",")
-        .map(|range| range.split_once("-").unwrap() )
-        .collect_vec()
+        .map(|range| {
+            let (start, end) = range
+                .split_once("-")
+                .ok_or_else(|| AoCError::parse_error(1, column_of(input, range), "expected range 'A-B'"))?;
+
+            // Make sure both ends are valid numbers:
+            parse_at::<u64>(start, 1, column_of(input, start))?;
+            parse_at::<u64>(end, 1, column_of(input, end))?;
+            Ok((start, end))
+        })
+        .collect()
 }
 
 #[cfg(test)]
@@ -91,14 +100,21 @@ mod tests {
     #[test]
     fn part1_example1() {
         let input = String::from("11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124");
-        let result = solve_part1(&input);
-        assert_eq!(u64::from(result), 1227775554);
+        let result = solve_part1(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 1227775554);
     }
 
     #[test]
     fn part2_example1() {
         let input = String::from("11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124");
-        let result = solve_part2(&input);
-        assert_eq!(u64::from(result), 4174379265);
+        let result = solve_part2(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 4174379265);
+    }
+
+    #[test]
+    fn part1_invalid_input() {
+        let input = String::from("11-22,95-1x5");
+        let result = solve_part1(&input);
+        assert!(matches!(result, Err(AoCError::ParseError { line: 1, column: 10, .. })));
     }
 }
@@ -1,27 +1,37 @@
-use aoc_core::AoCResult;
+use aoc_core::{AoCError, AoCResult};
 use std::collections::HashSet;
 use itertools::Itertools;
 
-aoc_core::make_day!(Day03);
+aoc_core::make_day!(Day03, 2025, 3);
 
-pub fn solve_part1(input: &String) -> AoCResult {
-    let res: u64 = input
+pub fn solve_part1(input: &str) -> Result<AoCResult, AoCError> {
+    let res = input
         .trim_end()
         .lines()
-        .map(|l| find_max_joltage(l, 2))
-        .sum();
+        .enumerate()
+        .map(|(i, l)| check_bank(l, i + 1).map(|_| find_max_joltage(l, 2)))
+        .sum::<Result<u64, AoCError>>()?;
 
-    AoCResult::Num(res)
+    Ok(AoCResult::Num(res))
 }
 
-pub fn solve_part2(input: &String) -> AoCResult {
-    let res: u64 = input
+pub fn solve_part2(input: &str) -> Result<AoCResult, AoCError> {
+    let res = input
         .trim_end()
         .lines()
-        .map(|l| find_max_joltage(l, 12))
-        .sum();
+        .enumerate()
+        .map(|(i, l)| check_bank(l, i + 1).map(|_| find_max_joltage(l, 12)))
+        .sum::<Result<u64, AoCError>>()?;
 
-    AoCResult::Num(res)
+    Ok(AoCResult::Num(res))
+}
+
+// Each battery in a bank is rated with a single digit
+fn check_bank(bank: &str, line: usize) -> Result<(), AoCError> {
+    match bank.chars().position(|c| !c.is_ascii_digit()) {
+        Some(column) => Err(AoCError::parse_error(line, column + 1, "expected a digit")),
+        None => Ok(()),
+    }
 }
 
 fn find_max_joltage(bank: &str, batt_num: usize) -> u64 {
@@ -65,7 +75,7 @@ fn find_rightmost_max_recursive(bank: &str, start: usize, end: usize, selected_b
         }
     }
 
-    return false;
+    false
 }
 
 
@@ -76,14 +86,21 @@ mod tests {
     #[test]
     fn part1_example1() {
         let input = String::from("987654321111111\r\n811111111111119\r\n234234234234278\r\n818181911112111");
-        let result = solve_part1(&input);
-        assert_eq!(u64::from(result), 357);
+        let result = solve_part1(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 357);
     }
 
     #[test]
     fn part2_example1() {
         let input = String::from("987654321111111\r\n811111111111119\r\n234234234234278\r\n818181911112111");
-        let result = solve_part2(&input);
-        assert_eq!(u64::from(result), 3121910778619);
+        let result = solve_part2(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 3121910778619);
+    }
+
+    #[test]
+    fn part1_invalid_input() {
+        let input = String::from("987654321111111\r\n81111a111111119");
+        let result = solve_part1(&input);
+        assert!(matches!(result, Err(AoCError::ParseError { line: 2, column: 6, .. })));
     }
 }
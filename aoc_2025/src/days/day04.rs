@@ -1,14 +1,15 @@
-use aoc_core::AoCResult;
+use aoc_core::{AoCError, AoCResult, IoContext, PuzzleInput};
+use aoc_core::io_context::Frame;
 use std::collections::HashSet;
 use itertools::Itertools;
 
-aoc_core::make_day!(Day04);
+aoc_core::make_day!(Day04, 2025, 4, io);
 
-pub fn solve_part1(input: &String) -> AoCResult {
-    let grid_width = input.find('\r').unwrap();
+pub fn solve_part1(input: &PuzzleInput, _io: &mut IoContext) -> Result<AoCResult, AoCError> {
+    let grid_width = input.find('\n').unwrap_or(input.len());
 
     let mut paper_rolls = HashSet::<(i32, i32)>::new();
-    for (i, c) in input.chars().filter(|c| c != &'\r' && c != &'\n').enumerate() {
+    for (i, c) in input.chars().filter(|c| c != &'\n').enumerate() {
         if c == '@' {
             paper_rolls.insert(((i % grid_width) as i32, (i / grid_width) as i32));
         }
@@ -19,20 +20,30 @@ pub fn solve_part1(input: &String) -> AoCResult {
         .filter(|(px, py)| has_fewer_than_4_neighbors(&paper_rolls, px, py))
         .count();
 
-    AoCResult::Num(result as u64)
+    Ok(AoCResult::Num(result as u64))
 }
 
-pub fn solve_part2(input: &String) -> AoCResult {
+pub fn solve_part2(input: &PuzzleInput, io: &mut IoContext) -> Result<AoCResult, AoCError> {
     let mut paper_rolls = get_paper_rolls(input);
     let orig_paper_rolls_count = paper_rolls.len();
 
+    // Size of the grid for showing frames:
+    let width = paper_rolls.iter().map(|(x, _)| x + 1).max().unwrap_or(0);
+    let height = paper_rolls.iter().map(|(_, y)| y + 1).max().unwrap_or(0);
+
     loop {
         let rolls_to_remove = paper_rolls.iter()
             .filter(|(px, py)| has_fewer_than_4_neighbors(&paper_rolls, px, py))
             .cloned()
             .collect_vec();
 
-        if rolls_to_remove.len() > 0 {
+        // Show each wave of paper rolls before it is removed:
+        if io.wants_frames() {
+            let removed = orig_paper_rolls_count - paper_rolls.len();
+            io.show_frame(&draw_grid(&paper_rolls, &rolls_to_remove, width, height, removed));
+        }
+
+        if !rolls_to_remove.is_empty() {
             for x in rolls_to_remove {
                 paper_rolls.remove(&x);
             }
@@ -42,20 +53,37 @@ pub fn solve_part2(input: &String) -> AoCResult {
         }
     }
 
-    AoCResult::Num((orig_paper_rolls_count - paper_rolls.len()) as u64)
+    Ok(AoCResult::Num((orig_paper_rolls_count - paper_rolls.len()) as u64))
 }
 
-fn get_paper_rolls(input: &String) -> HashSet<(i32, i32)> {
-    let grid_width = input.find('\r').unwrap();
+fn get_paper_rolls(input: &PuzzleInput) -> HashSet<(i32, i32)> {
+    let grid_width = input.find('\n').unwrap_or(input.len());
 
     input.chars()
-        .filter(|c| c != &'\r' && c != &'\n')
+        .filter(|c| c != &'\n')
         .enumerate()
         .filter(|(_, c)| c == &'@')
         .map(|(i, _)| ((i % grid_width) as i32, (i / grid_width) as i32) )
         .collect()
 }
 
+// Paper rolls as '@', the ones about to be removed as 'x'
+fn draw_grid(paper_rolls: &HashSet<(i32, i32)>, removing: &[(i32, i32)], width: i32, height: i32, removed: usize) -> Frame {
+    let lines = (0..height)
+        .map(|y| {
+            (0..width)
+                .map(|x| match (x, y) {
+                    p if removing.contains(&p) => 'x',
+                    p if paper_rolls.contains(&p) => '@',
+                    _ => '.',
+                })
+                .collect()
+        })
+        .collect();
+
+    Frame::new(lines, format!("Removed: {}", removed))
+}
+
 fn has_fewer_than_4_neighbors(grid: &HashSet<(i32, i32)>, px: &i32, py: &i32) -> bool {
     let mut neighbors = 0;
     for dy in -1..=1 {
@@ -78,15 +106,36 @@ mod tests {
 
     #[test]
     fn part1_example1() {
-        let input = String::from("..@@.@@@@.\r\n@@@.@.@.@@\r\n@@@@@.@.@@\r\n@.@@@@..@.\r\n@@.@@@@.@@\r\n.@@@@@@@.@\r\n.@.@.@.@@@\r\n@.@@@.@@@@\r\n.@@@@@@@@.\r\n@.@.@@@.@.");
-        let result = solve_part1(&input);
-        assert_eq!(u64::from(result), 13);
+        let input = PuzzleInput::from("..@@.@@@@.\r\n@@@.@.@.@@\r\n@@@@@.@.@@\r\n@.@@@@..@.\r\n@@.@@@@.@@\r\n.@@@@@@@.@\r\n.@.@.@.@@@\r\n@.@@@.@@@@\r\n.@@@@@@@@.\r\n@.@.@@@.@.");
+        let result = solve_part1(&input, &mut IoContext::silent()).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 13);
     }
 
     #[test]
     fn part2_example1() {
-        let input = String::from("..@@.@@@@.\r\n@@@.@.@.@@\r\n@@@@@.@.@@\r\n@.@@@@..@.\r\n@@.@@@@.@@\r\n.@@@@@@@.@\r\n.@.@.@.@@@\r\n@.@@@.@@@@\r\n.@@@@@@@@.\r\n@.@.@@@.@.");
-        let result = solve_part2(&input);
-        assert_eq!(u64::from(result), 43);
+        let input = PuzzleInput::from("..@@.@@@@.\r\n@@@.@.@.@@\r\n@@@@@.@.@@\r\n@.@@@@..@.\r\n@@.@@@@.@@\r\n.@@@@@@@.@\r\n.@.@.@.@@@\r\n@.@@@.@@@@\r\n.@@@@@@@@.\r\n@.@.@@@.@.");
+        let result = solve_part2(&input, &mut IoContext::silent()).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 43);
+    }
+
+    #[test]
+    fn part2_example1_lf() {
+        let input = PuzzleInput::from("..@@.@@@@.\n@@@.@.@.@@\n@@@@@.@.@@\n@.@@@@..@.\n@@.@@@@.@@\n.@@@@@@@.@\n.@.@.@.@@@\n@.@@@.@@@@\n.@@@@@@@@.\n@.@.@@@.@.\n");
+        let result = solve_part2(&input, &mut IoContext::silent()).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 43);
+    }
+    #[test]
+    fn part2_frames() {
+        let input = PuzzleInput::from("..@@.@@@@.\n@@@.@.@.@@\n@@@@@.@.@@\n@.@@@@..@.\n@@.@@@@.@@\n.@@@@@@@.@\n.@.@.@.@@@\n@.@@@.@@@@\n.@@@@@@@@.\n@.@.@@@.@.\n");
+        let mut frames = vec![];
+        let mut io = IoContext::silent().with_frames(&mut frames);
+        solve_part2(&input, &mut io).unwrap();
+        drop(io);
+
+        // One frame per wave of removed paper rolls and the final state:
+        assert_eq!(frames.len(), 10);
+        assert_eq!(frames[0].lines[0], "..xx.xx@x.");
+        assert_eq!(frames[9].lines[0], "..........");
+        assert_eq!(frames[9].caption, "Removed: 43");
     }
 }
@@ -1,48 +1,35 @@
-use aoc_core::AoCResult;
+use aoc_core::{AoCError, AoCResult, PuzzleInput};
 use std::cmp::max;
 use itertools::Itertools;
 
-aoc_core::make_day!(Day05);
+aoc_core::make_day!(Day05, 2025, 5);
 
-pub fn solve_part1(input: &String) -> AoCResult {
-    let (fresh_ingredient_ranges, ingredients) = input.split_once("\r\n\r\n").unwrap();
-
-    let id_ranges = fresh_ingredient_ranges
-        .trim()
-        .lines()
-        .map(|l| l.split_once("-").unwrap())
-        .map(|(start, end)| (start.parse::<u64>().unwrap(), end.parse::<u64>().unwrap()))
-        .collect_vec();
+pub fn solve_part1(input: &PuzzleInput) -> Result<AoCResult, AoCError> {
+    let (id_ranges, ingredients) = get_database(input)?;
 
     let fresh_ingredients = ingredients
-        .trim()
-        .lines()
-        .map(|l| l.parse::<u64>().unwrap())
+        .into_iter()
         .filter(|id| id_ranges.iter().any(|(start, end)| start <= id && id <= end ))
         .count();
 
-    AoCResult::Num(fresh_ingredients as u64)
+    Ok(AoCResult::Num(fresh_ingredients as u64))
 }
 
-pub fn solve_part2(input: &String) -> AoCResult {
-    let id_ranges = input
-        .split_once("\r\n\r\n").unwrap().0
-        .trim()
-        .lines()
-        .map(|l| l.split_once("-").unwrap())
-        .map(|(start, end)| (start.parse::<u64>().unwrap(), end.parse::<u64>().unwrap()))
+pub fn solve_part2(input: &PuzzleInput) -> Result<AoCResult, AoCError> {
+    let id_ranges = get_database(input)?.0
+        .into_iter()
         .sorted_by_key(|range| range.0)
         .collect_vec();
     
     // Merge ranges together:
-    let mut merged_range = id_ranges.iter().next().unwrap().clone();
+    let mut merged_range = *id_ranges.first().ok_or(AoCError::NoSolution)?;
     let mut non_overlapping_ranges = vec![];
     for range in id_ranges.iter().skip(1) {
         if range.0 <= merged_range.1 {
             merged_range = (merged_range.0, max(range.1, merged_range.1));
         } else {
             non_overlapping_ranges.push(merged_range);
-            merged_range = range.clone();
+            merged_range = *range;
         }
     }
     non_overlapping_ranges.push(merged_range);
@@ -52,7 +39,36 @@ pub fn solve_part2(input: &String) -> AoCResult {
         .map(|r| r.1 - r.0 + 1)
         .sum::<u64>();
 
-    AoCResult::Num(total_range)
+    Ok(AoCResult::Num(total_range))
+}
+
+type IdRange = (u64, u64);
+
+// Returns the fresh ingredient ID ranges and the available ingredient IDs
+// (both sections are separated by a blank line):
+fn get_database(input: &PuzzleInput) -> Result<(Vec<IdRange>, Vec<u64>), AoCError> {
+    let mut sections = input.paragraphs();
+
+    let id_ranges = sections.next()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|l| {
+            let (start, end) = l.split_once_checked("-")?;
+            Ok((start.parse()?, end.parse()?))
+        })
+        .collect::<Result<Vec<IdRange>, AoCError>>()?;
+
+    let ingredients = sections.next()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|l| l.parse())
+        .collect::<Result<Vec<u64>, AoCError>>()?;
+
+    if let Some(section) = sections.next() {
+        return Err(section[0].error("expected only two sections"));
+    }
+
+    Ok((id_ranges, ingredients))
 }
 
 #[cfg(test)]
@@ -61,15 +77,26 @@ mod tests {
 
     #[test]
     fn part1_example1() {
-        let input = String::from("3-5\r\n10-14\r\n16-20\r\n12-18\r\n\r\n1\r\n5\r\n8\r\n11\r\n17\r\n32");
-        let result = solve_part1(&input);
-        assert_eq!(u64::from(result), 3);
+        let input = PuzzleInput::from("3-5\r\n10-14\r\n16-20\r\n12-18\r\n\r\n1\r\n5\r\n8\r\n11\r\n17\r\n32");
+        let result = solve_part1(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 3);
     }
 
     #[test]
     fn part2_example1() {
-        let input = String::from("3-5\r\n10-14\r\n16-20\r\n12-18\r\n\r\n1\r\n5\r\n8\r\n11\r\n17\r\n32");
-        let result = solve_part2(&input);
-        assert_eq!(u64::from(result), 14);
+        let input = PuzzleInput::from("3-5\r\n10-14\r\n16-20\r\n12-18\r\n\r\n1\r\n5\r\n8\r\n11\r\n17\r\n32");
+        let result = solve_part2(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 14);
+    }
+
+    #[test]
+    fn part1_invalid_input() {
+        let input = PuzzleInput::from("3-5\r\n10-14\r\n16 20\r\n\r\n1\r\n5");
+        let result = solve_part1(&input);
+        assert!(matches!(result, Err(AoCError::ParseError { line: 3, column: 1, .. })));
+
+        let input = PuzzleInput::from("3-5\r\n\r\n1\r\n\r\n5");
+        let result = solve_part1(&input);
+        assert!(matches!(result, Err(AoCError::ParseError { line: 5, column: 1, .. })));
     }
 }
@@ -1,59 +1,81 @@
-use aoc_core::AoCResult;
+use aoc_core::{AoCError, AoCResult};
+use aoc_core::aoc_error::{column_of, parse_at};
 use itertools::Itertools;
 
-aoc_core::make_day!(Day06);
+aoc_core::make_day!(Day06, 2025, 6);
 
-pub fn solve_part1(input: &String) -> AoCResult {
-    let rows = input
-        .trim()
-        .lines()
+pub fn solve_part1(input: &str) -> Result<AoCResult, AoCError> {
+    let lines = input.trim_end().lines().collect_vec();
+    let rows = lines
+        .iter()
         .map(|l| l.split_whitespace().collect_vec())
         .collect_vec();
 
+    // Operators are in the last line:
+    let (ops, number_rows) = rows.split_last().ok_or(AoCError::NoSolution)?;
+
     let mut grand_total = 0;
-    for i in 0..rows[0].len() {
-        // Get operator from last line:
-        let op = rows.last().unwrap()[i];
-        
+    for (i, op) in ops.iter().enumerate() {
+        if *op != "+" && *op != "*" {
+            return Err(AoCError::parse_error(rows.len(), column_of(lines[rows.len() - 1], op), "expected '+' or '*'"));
+        }
+
         // Add or multiply numbers together:
-        let mut problem_result = if op == "+" { 0 } else { 1 };
-        for row in rows.iter().take(rows.len() - 1) {
-            if op == "+" {
-                problem_result += row[i].parse::<u64>().unwrap();
-            } else if op == "*" {
-                problem_result *= row[i].parse::<u64>().unwrap();
+        let mut problem_result = if *op == "+" { 0 } else { 1 };
+        for (y, row) in number_rows.iter().enumerate() {
+            let value = row
+                .get(i)
+                .ok_or_else(|| AoCError::parse_error(y + 1, lines[y].len() + 1, "missing number"))?;
+            let value: u64 = parse_at(value, y + 1, column_of(lines[y], value))?;
+
+            if *op == "+" {
+                problem_result += value;
+            } else {
+                problem_result *= value;
             }
         }
 
         grand_total += problem_result;
     }
 
-    AoCResult::Num(grand_total)
+    Ok(AoCResult::Num(grand_total))
 }
 
-pub fn solve_part2(input: &String) -> AoCResult {
+pub fn solve_part2(input: &str) -> Result<AoCResult, AoCError> {
     let rows = input
         .trim()
         .lines()
         .map(|l| l.chars().collect_vec())
         .collect_vec();
 
+    // Operators are in the last line:
+    let (ops, digit_rows) = rows.split_last().ok_or(AoCError::NoSolution)?;
+    let width = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+
     let mut grand_total = 0;
     let mut current_op = ' ';
     let mut current_result = 0;
-    for i in 0..rows[0].len() {
-        let op = rows.last().unwrap().get(i).unwrap_or(&' ');
+    for i in 0..width {
+        let op = ops.get(i).unwrap_or(&' ');
         if op != &' ' {
+            if op != &'+' && op != &'*' {
+                return Err(AoCError::parse_error(rows.len(), i + 1, "expected '+' or '*'"));
+            }
             current_op = *op;
             current_result = if op == &'+' { 0 } else { 1 };
         }
 
-        let num: u64 = rows.iter()
-            .take(rows.len() - 1)
-            .filter(|row| row[i] != ' ')
+        // Digits of the column from top to bottom:
+        let digits = digit_rows.iter()
+            .enumerate()
+            .filter_map(|(y, row)| row.get(i).filter(|c| **c != ' ').map(|c| (y, c)))
+            .map(|(y, c)| c.to_digit(10).ok_or_else(|| AoCError::parse_error(y + 1, i + 1, "expected a digit")))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let num: u64 = digits.iter()
             .rev()
             .enumerate()
-            .map(|(j, row)| 10u64.pow(j as u32) * (row[i].to_digit(10).unwrap() as u64))
+            .map(|(j, d)| 10u64.pow(j as u32) * (*d as u64))
             .sum();
 
         if num != 0 {
@@ -71,7 +93,7 @@ pub fn solve_part2(input: &String) -> AoCResult {
     // Result from last column:
     grand_total += current_result;
 
-    AoCResult::Num(grand_total)
+    Ok(AoCResult::Num(grand_total))
 }
 
 #[cfg(test)]
@@ -81,14 +103,21 @@ mod tests {
     #[test]
     fn part1_example1() {
         let input = String::from("123 328  51 64 \r\n 45 64  387 23 \r\n  6 98  215 314\r\n*   +   *   +  ");
-        let result = solve_part1(&input);
-        assert_eq!(u64::from(result), 4277556);
+        let result = solve_part1(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 4277556);
     }
 
     #[test]
     fn part2_example1() {
         let input = String::from("123 328  51 64 \r\n 45 64  387 23 \r\n  6 98  215 314\r\n*   +   *   +  ");
-        let result = solve_part2(&input);
-        assert_eq!(u64::from(result), 3263827);
+        let result = solve_part2(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 3263827);
+    }
+
+    #[test]
+    fn part1_invalid_input() {
+        let input = String::from("123 328\r\n 45 6x4\r\n*   +  ");
+        let result = solve_part1(&input);
+        assert!(matches!(result, Err(AoCError::ParseError { line: 2, column: 5, .. })));
     }
 }
@@ -1,15 +1,15 @@
-use aoc_core::AoCResult;
+use aoc_core::{AoCError, AoCResult, PuzzleInput};
 use std::collections::{HashSet, HashMap, VecDeque};
 use itertools::Itertools;
 
-aoc_core::make_day!(Day07);
+aoc_core::make_day!(Day07, 2025, 7);
 
-pub fn solve_part1(input: &String) -> AoCResult {
-    let manifold = TachyonManifold::new(input);
+pub fn solve_part1(input: &PuzzleInput) -> Result<AoCResult, AoCError> {
+    let manifold = TachyonManifold::new(input)?;
 
     let mut visited = HashSet::<(u64,u64)>::new();
     let mut queue = VecDeque::new();
-    queue.push_back(manifold.get_start());
+    queue.push_back(manifold.get_start()?);
 
     let mut splits = 0;
     while !queue.is_empty() {
@@ -25,28 +25,24 @@ pub fn solve_part1(input: &String) -> AoCResult {
             // Split left and right:
             splits += 1;
 
-            let left_pos = (cur_pos.0 - 1, cur_pos.1 + 1);
-            if visited.insert(left_pos) {
-                queue.push_back(left_pos);
-            }
-
-            let right_pos = (cur_pos.0 + 1, cur_pos.1 + 1);
-            if visited.insert(right_pos) {
-                queue.push_back(right_pos);
+            for next_pos in manifold.split(&cur_pos).into_iter().flatten() {
+                if visited.insert(next_pos) {
+                    queue.push_back(next_pos);
+                }
             }
         }
     }
 
-    AoCResult::Num(splits)
+    Ok(AoCResult::Num(splits))
 }
 
-pub fn solve_part2(input: &String) -> AoCResult {
-    let manifold = TachyonManifold::new(input);
+pub fn solve_part2(input: &PuzzleInput) -> Result<AoCResult, AoCError> {
+    let manifold = TachyonManifold::new(input)?;
 
     // Count visits at each point (with number of timelines):
     let mut visited = HashMap::<(u64,u64), u64>::new();
     let mut queue = VecDeque::new();
-    queue.push_back(manifold.get_start());
+    queue.push_back(manifold.get_start()?);
 
     let mut timelines = 0;
     while !queue.is_empty() {
@@ -68,25 +64,22 @@ pub fn solve_part2(input: &String) -> AoCResult {
             }
         } else {
             // Split left and right:
-            let left_pos = (cur_pos.0 - 1, cur_pos.1 + 1);
-            visited.entry(left_pos)
-                .and_modify(|e| *e += cur_timelines)
-                .or_insert_with(|| {
-                    queue.push_back(left_pos);
-                    cur_timelines
-                });
-
-            let right_pos = (cur_pos.0 + 1, cur_pos.1 + 1);
-            visited.entry(right_pos)
-                .and_modify(|e| *e += cur_timelines)
-                .or_insert_with(|| {
-                    queue.push_back(right_pos);
-                    cur_timelines
-                });
+            for next_pos in manifold.split(&cur_pos) {
+                if let Some(next_pos) = next_pos {
+                    visited.entry(next_pos)
+                        .and_modify(|e| *e += cur_timelines)
+                        .or_insert_with(|| {
+                            queue.push_back(next_pos);
+                            cur_timelines
+                        });
+                } else {
+                    timelines += cur_timelines;
+                }
+            }
         }
     }
 
-    AoCResult::Num(timelines)
+    Ok(AoCResult::Num(timelines))
 }
 
 struct TachyonManifold {
@@ -95,28 +88,38 @@ struct TachyonManifold {
 }
 
 impl TachyonManifold {
-    fn new(input: &String) -> Self {
-        TachyonManifold { 
-            width: input.find('\r').unwrap() as u64,
-            manifold: input
-                .trim_end()
-                .chars()
-                .filter(|c| !c.is_whitespace())
-                .collect_vec()
-         }
+    fn new(input: &PuzzleInput) -> Result<Self, AoCError> {
+        let grid = input.grid()?;
+        for (y, row) in grid.iter().enumerate() {
+            if let Some(x) = row.iter().position(|c| !matches!(c, '.' | 'S' | '^')) {
+                return Err(AoCError::parse_error(y + 1, x + 1, format!("unknown tile '{}'", row[x])));
+            }
+        }
+
+        Ok(TachyonManifold {
+            width: grid.first().map_or(0, |row| row.len()) as u64,
+            manifold: grid.concat(),
+        })
     }
 
-    fn get_start(&self) -> (u64,u64) {
-        let start_index = self.manifold.iter().find_position(|&c| c == &'S').unwrap().0 as u64;
-        (start_index % self.width, start_index / self.width)
+    fn get_start(&self) -> Result<(u64,u64), AoCError> {
+        let start_index = self.manifold.iter().find_position(|&c| c == &'S').ok_or(AoCError::NoSolution)?.0 as u64;
+        Ok((start_index % self.width, start_index / self.width))
     }
 
     fn is_valid(&self, pos: &(u64, u64)) -> bool {
-        ((pos.1 * self.width + pos.0) as usize) < self.manifold.len()
+        pos.0 < self.width && ((pos.1 * self.width + pos.0) as usize) < self.manifold.len()
     }
 
     fn is_pos_split(&self, pos: &(u64, u64)) -> bool {
-        self.manifold[(pos.1 * self.width + pos.0) as usize] == '^'
+        self.manifold.get((pos.1 * self.width + pos.0) as usize) == Some(&'^')
+    }
+
+    // Positions left and right below a splitter (None if the beam leaves the manifold)
+    fn split(&self, pos: &(u64, u64)) -> [Option<(u64, u64)>; 2] {
+        let left_pos = pos.0.checked_sub(1).map(|x| (x, pos.1 + 1));
+        let right_pos = (pos.0 + 1, pos.1 + 1);
+        [left_pos.filter(|p| self.is_valid(p)), Some(right_pos).filter(|p| self.is_valid(p))]
     }
 }
 
@@ -126,15 +129,22 @@ mod tests {
 
     #[test]
     fn part1_example1() {
-        let input = String::from(".......S.......\r\n...............\r\n.......^.......\r\n...............\r\n......^.^......\r\n...............\r\n.....^.^.^.....\r\n...............\r\n....^.^...^....\r\n...............\r\n...^.^...^.^...\r\n...............\r\n..^...^.....^..\r\n...............\r\n.^.^.^.^.^...^.\r\n...............");
-        let result = solve_part1(&input);
-        assert_eq!(u64::from(result), 21);
+        let input = PuzzleInput::from(".......S.......\r\n...............\r\n.......^.......\r\n...............\r\n......^.^......\r\n...............\r\n.....^.^.^.....\r\n...............\r\n....^.^...^....\r\n...............\r\n...^.^...^.^...\r\n...............\r\n..^...^.....^..\r\n...............\r\n.^.^.^.^.^...^.\r\n...............");
+        let result = solve_part1(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 21);
     }
 
     #[test]
     fn part2_example1() {
-        let input = String::from(".......S.......\r\n...............\r\n.......^.......\r\n...............\r\n......^.^......\r\n...............\r\n.....^.^.^.....\r\n...............\r\n....^.^...^....\r\n...............\r\n...^.^...^.^...\r\n...............\r\n..^...^.....^..\r\n...............\r\n.^.^.^.^.^...^.\r\n...............");
-        let result = solve_part2(&input);
-        assert_eq!(u64::from(result), 40);
+        let input = PuzzleInput::from(".......S.......\r\n...............\r\n.......^.......\r\n...............\r\n......^.^......\r\n...............\r\n.....^.^.^.....\r\n...............\r\n....^.^...^....\r\n...............\r\n...^.^...^.^...\r\n...............\r\n..^...^.....^..\r\n...............\r\n.^.^.^.^.^...^.\r\n...............");
+        let result = solve_part2(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 40);
+    }
+
+    #[test]
+    fn part1_invalid_input() {
+        let input = PuzzleInput::from("..S..\r\n..^..\r\n.....\r\n..#..");
+        let result = solve_part1(&input);
+        assert!(matches!(result, Err(AoCError::ParseError { line: 4, column: 3, .. })));
     }
 }
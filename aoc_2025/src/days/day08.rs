@@ -1,25 +1,22 @@
-use aoc_core::AoCResult;
+use aoc_core::{AoCError, AoCResult, Params};
+use aoc_core::aoc_error::{column_of, parse_at};
 use std::{cmp::min, collections::{HashMap}};
 use itertools::Itertools;
 
-aoc_core::make_day!(Day08);
+aoc_core::make_day!(Day08, 2025, 8, params: [connections = "1000"]);
 
-pub fn solve_part1(input: &String) -> AoCResult {
-    let result = combine_shortest_conns(input, 1000);
-    AoCResult::Num(result)
+pub fn solve_part1(input: &str, params: &Params) -> Result<AoCResult, AoCError> {
+    let result = combine_shortest_conns(input, params.get_positive("connections")?)?;
+    Ok(AoCResult::Num(result))
 }
 
-pub fn solve_part2(input: &String) -> AoCResult {
-    let result = combine_all_conns(input);
-    AoCResult::Num(result)
+pub fn solve_part2(input: &str, _params: &Params) -> Result<AoCResult, AoCError> {
+    let result = combine_all_conns(input)?;
+    Ok(AoCResult::Num(result))
 }
 
-fn combine_shortest_conns(input: &String, n: usize) -> u64 {
-    let boxes: Vec<(i64,i64,i64)> = input
-        .trim_end()
-        .lines()
-        .map(|l| l.split(",").map(|x| x.parse::<i64>().unwrap()).collect_tuple().unwrap())
-        .collect_vec();
+fn combine_shortest_conns(input: &str, n: usize) -> Result<u64, AoCError> {
+    let boxes = get_boxes(input)?;
 
     // At the beginning, each box is a circuit by itself:
     let mut circuits = boxes.iter()
@@ -27,7 +24,7 @@ fn combine_shortest_conns(input: &String, n: usize) -> u64 {
         .map(|(i, x)| (*x,i))
         .collect::<HashMap<(i64,i64,i64), usize>>();
 
-    // Connect together the 1000 pairs of junction boxes which are closest together
+    // Connect together the n pairs of junction boxes which are closest together
     for (box1, box2) in boxes.iter()
         .tuple_combinations()
         .sorted_by_cached_key(|(a,b)| (a.0 - b.0).pow(2) + (a.1 - b.1).pow(2) + (a.2 - b.2).pow(2) )
@@ -56,15 +53,11 @@ fn combine_shortest_conns(input: &String, n: usize) -> u64 {
         .take(3);
 
     // Multiply together the sizes of the three largest circuits:
-    top3.fold(1, |acc, x| acc * x) as u64
+    Ok(top3.product::<usize>() as u64)
 }
 
-fn combine_all_conns(input: &String) -> u64 {
-    let boxes: Vec<(i64,i64,i64)> = input
-        .trim_end()
-        .lines()
-        .map(|l| l.split(",").map(|x| x.parse::<i64>().unwrap()).collect_tuple().unwrap())
-        .collect_vec();
+fn combine_all_conns(input: &str) -> Result<u64, AoCError> {
+    let boxes = get_boxes(input)?;
 
     // At the beginning, each box is a circuit by itself:
     let mut circuits = boxes.iter()
@@ -72,8 +65,6 @@ fn combine_all_conns(input: &String) -> u64 {
         .map(|(i, x)| (*x,i))
         .collect::<HashMap<(i64,i64,i64), usize>>();
 
-    let mut result: u64 = 0;
-
     // Connect together all pairs of junction boxes until they are all in one circuit:
     for (box1, box2) in boxes.iter()
         .tuple_combinations()
@@ -91,29 +82,46 @@ fn combine_all_conns(input: &String) -> u64 {
 
             // Check if all boxes are connected:
             if circuits.iter().all(|(_, c)| c == &res_circuit) {
-                result = (box1.0 * box2.0) as u64;
-                break;
+                return Ok((box1.0 * box2.0) as u64);
             }
     }
 
-    result
+    Err(AoCError::NoSolution)
+}
+
+fn get_boxes(input: &str) -> Result<Vec<(i64,i64,i64)>, AoCError> {
+    input
+        .trim_end()
+        .lines()
+        .enumerate()
+        .map(|(i, l)| {
+            let l = l.trim_end();
+            l.split(",")
+                .map(|x| parse_at::<i64>(x, i + 1, column_of(l, x)))
+                .collect::<Result<Vec<_>, _>>()?
+                .into_iter()
+                .collect_tuple()
+                .ok_or_else(|| AoCError::parse_error(i + 1, 1, "expected three coordinates 'X,Y,Z'"))
+        })
+        .collect()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{IoContext, PuzzleInput};
 
     #[test]
     fn part1_example1() {
-        let input = String::from("162,817,812\r\n57,618,57\r\n906,360,560\r\n592,479,940\r\n352,342,300\r\n466,668,158\r\n542,29,236\r\n431,825,988\r\n739,650,466\r\n52,470,668\r\n216,146,977\r\n819,987,18\r\n117,168,530\r\n805,96,715\r\n346,949,466\r\n970,615,88\r\n941,993,340\r\n862,61,35\r\n984,92,344\r\n425,690,689");
-        let result = combine_shortest_conns(&input, 10);
-        assert_eq!(u64::from(result), 40);
+        let input = PuzzleInput::from("162,817,812\r\n57,618,57\r\n906,360,560\r\n592,479,940\r\n352,342,300\r\n466,668,158\r\n542,29,236\r\n431,825,988\r\n739,650,466\r\n52,470,668\r\n216,146,977\r\n819,987,18\r\n117,168,530\r\n805,96,715\r\n346,949,466\r\n970,615,88\r\n941,993,340\r\n862,61,35\r\n984,92,344\r\n425,690,689");
+        let result = Day08 {}.run_part1(&input, &Params::from([("connections", "10")]), &mut IoContext::silent()).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 40);
     }
 
     #[test]
     fn part2_example1() {
-        let input = String::from("162,817,812\r\n57,618,57\r\n906,360,560\r\n592,479,940\r\n352,342,300\r\n466,668,158\r\n542,29,236\r\n431,825,988\r\n739,650,466\r\n52,470,668\r\n216,146,977\r\n819,987,18\r\n117,168,530\r\n805,96,715\r\n346,949,466\r\n970,615,88\r\n941,993,340\r\n862,61,35\r\n984,92,344\r\n425,690,689");
-        let result = combine_all_conns(&input);
-        assert_eq!(u64::from(result), 25272);
+        let input = PuzzleInput::from("162,817,812\r\n57,618,57\r\n906,360,560\r\n592,479,940\r\n352,342,300\r\n466,668,158\r\n542,29,236\r\n431,825,988\r\n739,650,466\r\n52,470,668\r\n216,146,977\r\n819,987,18\r\n117,168,530\r\n805,96,715\r\n346,949,466\r\n970,615,88\r\n941,993,340\r\n862,61,35\r\n984,92,344\r\n425,690,689");
+        let result = Day08 {}.run_part2(&input, &Params::new(), &mut IoContext::silent()).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 25272);
     }
 }
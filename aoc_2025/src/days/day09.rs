@@ -1,30 +1,24 @@
-use aoc_core::AoCResult;
+use aoc_core::{AoCError, AoCResult};
+use aoc_core::aoc_error::{column_of, parse_at};
 use std::{cmp::{Reverse, max, min}};
 use itertools::Itertools;
 
-aoc_core::make_day!(Day09);
+aoc_core::make_day!(Day09, 2025, 9);
 
-pub fn solve_part1(input: &String) -> AoCResult {
-    let max_area = input
-        .trim_end()
-        .lines()
-        .map(|l| l.split_once(",").unwrap())
-        .map(|(a,b)| (a.parse::<i64>().unwrap(), b.parse::<i64>().unwrap()))
+pub fn solve_part1(input: &str) -> Result<AoCResult, AoCError> {
+    let max_area = get_red_tiles(input)?
+        .into_iter()
         .tuple_combinations()
         .map(|(t1, t2)| ((t1.0 - t2.0).abs() + 1) * ((t1.1 - t2.1).abs() + 1))
         .max()
-        .unwrap();
+        .ok_or(AoCError::NoSolution)?;
 
-    AoCResult::Num(max_area as u64)
+    Ok(AoCResult::Int(max_area))
 }
 
-pub fn solve_part2(input: &String) -> AoCResult {
-    let red_tiles = input
-        .trim_end()
-        .lines()
-        .map(|l| l.split_once(",").unwrap())
-        .map(|(a,b)| (a.parse::<i64>().unwrap(), b.parse::<i64>().unwrap()))
-        .collect_vec();
+pub fn solve_part2(input: &str) -> Result<AoCResult, AoCError> {
+    let red_tiles = get_red_tiles(input)?;
+    let (first, last) = red_tiles.first().zip(red_tiles.last()).ok_or(AoCError::NoSolution)?;
 
     let mut outer_edges: Vec<((i64, i64), (i64, i64))> = red_tiles.iter()
         .cloned()
@@ -32,7 +26,7 @@ pub fn solve_part2(input: &String) -> AoCResult {
         .collect_vec();
 
     // Add edge between last and first point:
-    outer_edges.push((red_tiles[0], red_tiles.last().unwrap().clone()));
+    outer_edges.push((*first, *last));
 
     // Order edges so coordinates of the first point are smaller than coordinates of the second point
     // (which will make later comparisons easier!)
@@ -52,19 +46,14 @@ pub fn solve_part2(input: &String) -> AoCResult {
     // A rectangle is assumed to be valid, if no other edges cross it (this is not sufficient in 
     // the general case, but works for the input!)
     let mut largest_area = 0;
-    for (p1, p2) in input
-        .trim_end()
-        .lines()
-        .map(|l| l.split_once(",").unwrap())
-        .map(|(a,b)| (a.parse::<i64>().unwrap(), b.parse::<i64>().unwrap()))
+    for (p1, p2) in red_tiles
+        .iter()
+        .cloned()
         .tuple_combinations()
         .sorted_by_cached_key(|(t1, t2)| {
             let area = ((t1.0 - t2.0).abs() + 1) * ((t1.1 - t2.1).abs() + 1);
             Reverse(area)
         }) {
-            if (p1 == (9,5) && p2 == (2,3)) || (p2 == (9,5) && p1 == (2,3)) {
-                println!("Here!");
-            }
             // Top left corner of the current rectangle:
             let x0 = min(p1.0, p2.0);
             let y0 = min(p1.1, p2.1);
@@ -87,10 +76,26 @@ pub fn solve_part2(input: &String) -> AoCResult {
             }
 
             largest_area = (x1 - x0 + 1) * (y1 - y0 + 1);
+            aoc_core::debug!("Largest valid rectangle: {:?} - {:?}", p1, p2);
             break;
         }
     
-    AoCResult::Num(largest_area as u64)
+    Ok(AoCResult::Int(largest_area))
+}
+
+fn get_red_tiles(input: &str) -> Result<Vec<(i64, i64)>, AoCError> {
+    input
+        .trim_end()
+        .lines()
+        .enumerate()
+        .map(|(i, l)| {
+            let l = l.trim_end();
+            let (a, b) = l
+                .split_once(",")
+                .ok_or_else(|| AoCError::parse_error(i + 1, 1, "expected tile position 'X,Y'"))?;
+            Ok((parse_at(a, i + 1, 1)?, parse_at(b, i + 1, column_of(l, b))?))
+        })
+        .collect()
 }
 
 #[cfg(test)]
@@ -100,14 +105,20 @@ mod tests {
     #[test]
     fn part1_example1() {
         let input = String::from("7,1\r\n11,1\r\n11,7\r\n9,7\r\n9,5\r\n2,5\r\n2,3\r\n7,3");
-        let result = solve_part1(&input);
-        assert_eq!(u64::from(result), 50);
+        let result = solve_part1(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 50);
     }
 
     #[test]
     fn part2_example1() {
         let input = String::from("7,1\r\n11,1\r\n11,7\r\n9,7\r\n9,5\r\n2,5\r\n2,3\r\n7,3");
-        let result = solve_part2(&input);
-        assert_eq!(u64::from(result), 24);
+        let result = solve_part2(&input).unwrap();
+        assert_eq!(u64::try_from(result).unwrap(), 24);
+    }
+
+    #[test]
+    fn part2_no_tiles() {
+        let result = solve_part2("");
+        assert!(matches!(result, Err(AoCError::NoSolution)));
     }
 }
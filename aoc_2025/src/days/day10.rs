@@ -1,46 +1,39 @@
-use aoc_core::AoCResult;
+use aoc_core::{AoCError, AoCResult, PuzzleInput};
+use aoc_core::puzzle_input::Line;
 use std::cmp::min;
 use itertools::Itertools;
 
-aoc_core::make_day!(Day10);
+aoc_core::make_day!(Day10, 2025, 10, parsed: Vec<Machine>);
 
-pub fn solve_part1(input: &String) -> AoCResult {
-    let machines = input
-        .trim_end()
-        .lines()
-        .map(|l| Machine::new(l))
-        .collect_vec();
+pub fn parse(input: &PuzzleInput) -> Result<Vec<Machine>, AoCError> {
+    input.lines().map(Machine::new).try_collect()
+}
 
+pub fn solve_part1(machines: &[Machine]) -> Result<AoCResult, AoCError> {
     let total_button_presses: u64 = machines.iter()
         .map(|m| m.get_min_button_presses())
         .sum();
 
-    AoCResult::Num(total_button_presses)
+    Ok(AoCResult::Num(total_button_presses))
 }
 
-pub fn solve_part2(input: &String) -> AoCResult {
-    let machines = input
-        .trim_end()
-        .lines()
-        .map(|l| Machine::new(l))
-        .collect_vec();
-
+pub fn solve_part2(machines: &[Machine]) -> Result<AoCResult, AoCError> {
     let total_button_presses = machines.iter()
         .map(|m| m.configure_joltage_counters())
         .sum();
     
-    AoCResult::Num(total_button_presses)
+    Ok(AoCResult::Num(total_button_presses))
 }
 
 
-struct Machine {
+pub struct Machine {
     lights_target: u64,
     buttons: Vec<u64>,
     joltage_reqs: Vec<i64>
 }
 
 impl Machine {
-    fn new(input: &str) -> Self {
+    fn new(input: Line) -> Result<Self, AoCError> {
         // Convert light diagram into numeric value
         let mut lights_target = 0u64;
 
@@ -50,34 +43,46 @@ impl Machine {
         // Convert joltage requirements into list of values:
         let mut joltags_reqs = vec![];
 
-        for s in input.split(" ") {
+        for s in input.trim().split(" ") {
+            // Every part is enclosed in brackets:
+            if s.len() < 2 {
+                return Err(s.error("expected '[...]', '(...)' or '{...}'"));
+            }
+            let inner = s.slice(&s[1..s.len() - 1]);
+
             if s.starts_with("[") {
                 // Light diagram:
-                for (i, c) in s[1..s.len() - 1].char_indices() {
+                for (i, c) in inner.char_indices() {
                     if c == '#' {
-                        lights_target |= 1 << i;
+                        lights_target |= 1u64.checked_shl(i as u32)
+                            .ok_or_else(|| s.error("more than 64 lights"))?;
                     }
                 }
 
             } else if s.starts_with("(") {
                 // Button:
                 let mut btn_value = 0u64;
-                for b in s[1..s.len() - 1].split(",") {
-                    btn_value |= 1 << b.parse::<u64>().unwrap();
+                for b in inner.csv_ints::<u64>()? {
+                    btn_value |= u32::try_from(b).ok()
+                        .and_then(|b| 1u64.checked_shl(b))
+                        .ok_or_else(|| s.error(format!("light {b} out of range")))?;
                 }
                 buttons_values.push(btn_value);
 
             } else if s.starts_with("{") {
                 // Joltages:
-                joltags_reqs = s[1..s.len() - 1].split(",").map(|n| n.parse().unwrap()).collect();
+                joltags_reqs = inner.csv_ints()?;
+                if joltags_reqs.len() > 64 {
+                    return Err(s.error("more than 64 joltage counters"));
+                }
             }
         }
 
-        Machine {
-            lights_target: lights_target,
+        Ok(Machine {
+            lights_target,
             buttons: buttons_values,
             joltage_reqs: joltags_reqs
-        }
+        })
     }
 
     fn get_min_button_presses(&self) -> u64 {
@@ -90,23 +95,23 @@ impl Machine {
             }
         }
 
-        return 0;
+        0
     }
 
     // Get minimum button presses for joltage counters
     // (solution implemented from: https://www.reddit.com/r/adventofcode/comments/1pk87hl/2025_day_10_part_2_bifurcate_your_way_to_victory/)
     fn configure_joltage_counters(&self) -> u64 {
-        self.configure_joltage_counters_recursive(&self.joltage_reqs, 0).unwrap()
+        self.configure_joltage_counters_recursive(&self.joltage_reqs).unwrap()
     }
 
-    fn configure_joltage_counters_recursive(&self, joltages: &Vec<i64>, debug_level: usize) -> Option<u64> {
+    fn configure_joltage_counters_recursive(&self, joltages: &[i64]) -> Option<u64> {
         let mut min_button_presses = None;
 
         // Convert joltages into light pattern:
         let lights_target = joltages.iter()
             .enumerate()
             .map(|(i,j)| if j % 2 == 0 { 0 } else { 1 << i } )
-            .fold(0, |acc,x| acc + x);
+            .sum::<u64>();
 
         // Reduce required joltages by combining button presses that produce the required light pattern:
         // (same code as get_min_button_presses())
@@ -116,7 +121,7 @@ impl Machine {
                 let result = combo.iter().fold(0u64, |acc, x| acc ^ *x);
                 if result == lights_target {
                     // Valid combo:
-                    valid_combos.push(combo.iter().cloned().cloned().collect_vec());
+                    valid_combos.push(combo.iter().copied().copied().collect_vec());
                 }
             }
         }
@@ -128,7 +133,7 @@ impl Machine {
 
         for btns in valid_combos {
             // Reduce required joltage by the button presses:
-            let mut new_joltages = joltages.clone();
+            let mut new_joltages = joltages.to_vec();
             for (i, j) in new_joltages.iter_mut().enumerate() {
                 for &b in btns.iter() {
                     if b & (1 << i) != 0 {
@@ -148,13 +153,8 @@ impl Machine {
             } else {
                 // if all values are even, divide by 2 and recurse
                 for j in new_joltages.iter_mut() { *j /= 2; }
-                let recursive_result = self.configure_joltage_counters_recursive(&new_joltages, debug_level + 1);
-                if let Some(result_val) = recursive_result {
-                    Some(btns.len() as u64 + (2 * result_val))
-                } else { 
-                    // No solution found:
-                    None
-                }
+                let recursive_result = self.configure_joltage_counters_recursive(&new_joltages);
+                recursive_result.map(|result_val| btns.len() as u64 + (2 * result_val))
             };
 
             if min_button_presses.is_none() {
@@ -173,16 +173,20 @@ mod tests {
     use super::*;
 
     #[test]
-    fn part1_example1() {
-        let input = String::from("[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}\r\n[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}\r\n[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}");
-        let result = solve_part1(&input);
-        assert_eq!(u64::from(result), 7);
+    fn part1_invalid_input() {
+        let input = PuzzleInput::from("[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}\r\n[...#.] (0,2,3,4) (2,x) {7,5,12,7,2}");
+        let result = parse(&input);
+        assert!(matches!(result, Err(AoCError::ParseError { line: 2, column: 22, .. })));
     }
 
     #[test]
-    fn part2_example1() {
-        let input = String::from("[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}\r\n[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}\r\n[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}");
-        let result = solve_part2(&input);
-        assert_eq!(u64::from(result), 33);
+    fn part1_too_many_lights() {
+        let input = PuzzleInput::from("[.##.] (3) (1,64) {3,5,4,7}");
+        let result = parse(&input);
+        assert!(matches!(result, Err(AoCError::ParseError { line: 1, column: 12, .. })));
+
+        let input = PuzzleInput::from(format!("[{}] (3) {{3}}", "#".repeat(65)));
+        let result = parse(&input);
+        assert!(matches!(result, Err(AoCError::ParseError { line: 1, column: 1, .. })));
     }
 }
@@ -1,9 +1,9 @@
-use aoc_core::AoCResult;
-use itertools::Itertools;
+use aoc_core::{AoCError, AoCResult};
+use aoc_core::aoc_error::{column_of, parse_at};
 
-aoc_core::make_day!(Day12);
+aoc_core::make_day!(Day12, 2025, 12);
 
-pub fn solve_part1(input: &String) -> AoCResult {
+pub fn solve_part1(input: &str) -> Result<AoCResult, AoCError> {
     // Use following approximations specific to my input:
     // - Shape [0]: two of these can fit into a 4x4 square
     // - Shape [2] fits together with shape [4] into a 3x5 rectangle
@@ -14,16 +14,24 @@ pub fn solve_part1(input: &String) -> AoCResult {
     let regions = input
         .trim_end()
         .lines()
-        .skip_while(|l| !l.contains('x'))
-        .map(|l| l.split_once(':').unwrap())
-        .map(|(dims, presents)| {
-            let dimensions = dims.split_once('x').unwrap();
-            Region {
-                size: dimensions.0.parse::<u64>().unwrap() * dimensions.1.parse::<u64>().unwrap(),
-                presents: presents.split_whitespace().map(|p| p.parse().unwrap()).collect()
-            }
+        .enumerate()
+        .skip_while(|(_, l)| !l.contains('x'))
+        .map(|(i, l)| {
+            let (dims, presents) = l
+                .split_once(':')
+                .ok_or_else(|| AoCError::parse_error(i + 1, 1, "expected region 'WxH: presents...'"))?;
+            let (width, height) = dims
+                .split_once('x')
+                .ok_or_else(|| AoCError::parse_error(i + 1, 1, "expected dimensions 'WxH'"))?;
+            Ok(Region {
+                size: parse_at::<u64>(width, i + 1, 1)? * parse_at::<u64>(height, i + 1, column_of(l, height))?,
+                presents: presents
+                    .split_whitespace()
+                    .map(|p| parse_at(p, i + 1, column_of(l, p)))
+                    .collect::<Result<_, _>>()?,
+            })
         })
-        .collect_vec();
+        .collect::<Result<Vec<_>, AoCError>>()?;
 
     let mut regions_that_fit_presents = 0;
 
@@ -68,11 +76,11 @@ pub fn solve_part1(input: &String) -> AoCResult {
         }
     }
 
-    AoCResult::Num(regions_that_fit_presents)
+    Ok(AoCResult::Num(regions_that_fit_presents))
 }
 
-pub fn solve_part2(_input: &String) -> AoCResult {
-    AoCResult::NotImplemented
+pub fn solve_part2(_input: &str) -> Result<AoCResult, AoCError> {
+    Ok(AoCResult::NotImplemented)
 }
 
 struct Region {
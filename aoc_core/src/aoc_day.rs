@@ -1,23 +1,329 @@
+use crate::aoc_error::AoCError;
 use crate::aoc_result::AoCResult;
-pub trait AoCDay {
-    fn run_part1(&self, input: &String) -> AoCResult;
-    fn run_part2(&self, input: &String) -> AoCResult;
+use crate::io_context::IoContext;
+use crate::params::{ParamInfo, Params};
+use crate::puzzle_input::PuzzleInput;
+use std::any::Any;
+
+pub trait AoCDay: Sync {
+    // Number of the day, e.g. for error messages
+    fn day(&self) -> u8;
+
+    // Parameters the solver accepts, with their default values
+    fn params(&self) -> &'static [ParamInfo] {
+        &[]
+    }
+
+    fn run_part1(&self, input: &PuzzleInput, params: &Params, io: &mut IoContext) -> Result<AoCResult, AoCError>;
+    fn run_part2(&self, input: &PuzzleInput, params: &Params, io: &mut IoContext) -> Result<AoCResult, AoCError>;
+
+    // Parses the input once so that both parts can be solved from it. Days that do not
+    // implement `ParseOnce` keep the raw input and parse it within each part.
+    fn parse(&self, input: &PuzzleInput) -> Result<Parsed, AoCError> {
+        Ok(Parsed::new(input.clone()))
+    }
+
+    fn solve_parsed(&self, part: u8, parsed: &Parsed, params: &Params, io: &mut IoContext) -> Result<AoCResult, AoCError> {
+        let input = parsed.get::<PuzzleInput>();
+        match part {
+            1 => self.run_part1(input, params, io),
+            2 => self.run_part2(input, params, io),
+            _ => Err(AoCError::InvalidPart(self.day(), part)),
+        }
+    }
+}
+
+// Days parsing their input once for both parts (`params` include the defaults of the day)
+pub trait ParseOnce {
+    type Parsed: Send + Sync + 'static;
+
+    fn parse(input: &PuzzleInput) -> Result<Self::Parsed, AoCError>;
+    fn part1(parsed: &Self::Parsed, params: &Params, io: &mut IoContext) -> Result<AoCResult, AoCError>;
+    fn part2(parsed: &Self::Parsed, params: &Params, io: &mut IoContext) -> Result<AoCResult, AoCError>;
+}
+
+// Parsed input of any day, as returned by `AoCDay::parse`
+pub struct Parsed(Box<dyn Any + Send + Sync>);
+
+impl Parsed {
+    pub fn new<T: Send + Sync + 'static>(value: T) -> Self {
+        Parsed(Box::new(value))
+    }
+
+    // Value has to be passed back to the day which parsed it, so the type always matches
+    pub fn get<T: 'static>(&self) -> &T {
+        self.0.downcast_ref().expect("parsed input passed to a different day")
+    }
 }
 
 #[macro_export]
 macro_rules! make_day {
-    ($struct_name:ident) => {
-        use crate::AoCDay;
-        pub struct $struct_name {}
+    ($struct_name:ident, $year:literal, $day:literal) => {
+        $crate::make_day!($struct_name, $year, $day, interactive: false);
+    };
+    ($struct_name:ident, $year:literal, $day:literal, interactive) => {
+        $crate::make_day!($struct_name, $year, $day, interactive: true);
+    };
+    ($struct_name:ident, $year:literal, $day:literal, interactive, io) => {
+        $crate::make_day!($struct_name, $year, $day, interactive: true, io);
+    };
+    ($struct_name:ident, $year:literal, $day:literal, io) => {
+        $crate::make_day!($struct_name, $year, $day, interactive: false, io);
+    };
+    // Solvers without parameters: solve_partN(input)
+    ($struct_name:ident, $year:literal, $day:literal, interactive: $interactive:literal) => {
+        $crate::make_day!(@day $struct_name, $year, $day, $interactive);
 
         impl AoCDay for $struct_name {
-            fn run_part1(&self, input: &String) -> AoCResult {
+            fn day(&self) -> u8 {
+                $day
+            }
+
+            fn run_part1(
+                &self,
+                input: &$crate::PuzzleInput,
+                params: &$crate::Params,
+                _io: &mut $crate::IoContext,
+            ) -> Result<AoCResult, $crate::AoCError> {
+                params.with_defaults(self.params())?;
                 solve_part1(input)
             }
 
-            fn run_part2(&self, input: &String) -> AoCResult {
+            fn run_part2(
+                &self,
+                input: &$crate::PuzzleInput,
+                params: &$crate::Params,
+                _io: &mut $crate::IoContext,
+            ) -> Result<AoCResult, $crate::AoCError> {
+                params.with_defaults(self.params())?;
                 solve_part2(input)
             }
         }
     };
+    // Solvers reading user input or showing frames: solve_partN(input, io)
+    ($struct_name:ident, $year:literal, $day:literal, interactive: $interactive:literal, io) => {
+        $crate::make_day!(@day $struct_name, $year, $day, $interactive);
+
+        impl AoCDay for $struct_name {
+            fn day(&self) -> u8 {
+                $day
+            }
+
+            fn run_part1(
+                &self,
+                input: &$crate::PuzzleInput,
+                params: &$crate::Params,
+                io: &mut $crate::IoContext,
+            ) -> Result<AoCResult, $crate::AoCError> {
+                params.with_defaults(self.params())?;
+                solve_part1(input, io)
+            }
+
+            fn run_part2(
+                &self,
+                input: &$crate::PuzzleInput,
+                params: &$crate::Params,
+                io: &mut $crate::IoContext,
+            ) -> Result<AoCResult, $crate::AoCError> {
+                params.with_defaults(self.params())?;
+                solve_part2(input, io)
+            }
+        }
+    };
+    // Solvers parsing the input once: parse(input) -> Result<$parsed, AoCError>, solve_partN(&$parsed)
+    ($struct_name:ident, $year:literal, $day:literal, parsed: $parsed:ty) => {
+        $crate::make_day!(@day $struct_name, $year, $day, false);
+
+        impl $crate::aoc_day::ParseOnce for $struct_name {
+            type Parsed = $parsed;
+
+            fn parse(input: &$crate::PuzzleInput) -> Result<$parsed, $crate::AoCError> {
+                parse(input)
+            }
+
+            fn part1(
+                parsed: &$parsed,
+                _params: &$crate::Params,
+                _io: &mut $crate::IoContext,
+            ) -> Result<AoCResult, $crate::AoCError> {
+                solve_part1(parsed)
+            }
+
+            fn part2(
+                parsed: &$parsed,
+                _params: &$crate::Params,
+                _io: &mut $crate::IoContext,
+            ) -> Result<AoCResult, $crate::AoCError> {
+                solve_part2(parsed)
+            }
+        }
+
+        $crate::make_day!(@parse_once $struct_name, $day);
+    };
+    // Same, but showing frames: solve_partN(&$parsed, io)
+    ($struct_name:ident, $year:literal, $day:literal, parsed: $parsed:ty, io) => {
+        $crate::make_day!($struct_name, $year, $day, parsed: $parsed, interactive: false, io);
+    };
+    // Same, but reading user input: solve_partN(&$parsed, io)
+    ($struct_name:ident, $year:literal, $day:literal, parsed: $parsed:ty, interactive, io) => {
+        $crate::make_day!($struct_name, $year, $day, parsed: $parsed, interactive: true, io);
+    };
+    ($struct_name:ident, $year:literal, $day:literal, parsed: $parsed:ty, interactive: $interactive:literal, io) => {
+        $crate::make_day!(@day $struct_name, $year, $day, $interactive);
+
+        impl $crate::aoc_day::ParseOnce for $struct_name {
+            type Parsed = $parsed;
+
+            fn parse(input: &$crate::PuzzleInput) -> Result<$parsed, $crate::AoCError> {
+                parse(input)
+            }
+
+            fn part1(
+                parsed: &$parsed,
+                _params: &$crate::Params,
+                io: &mut $crate::IoContext,
+            ) -> Result<AoCResult, $crate::AoCError> {
+                solve_part1(parsed, io)
+            }
+
+            fn part2(
+                parsed: &$parsed,
+                _params: &$crate::Params,
+                io: &mut $crate::IoContext,
+            ) -> Result<AoCResult, $crate::AoCError> {
+                solve_part2(parsed, io)
+            }
+        }
+
+        $crate::make_day!(@parse_once $struct_name, $day);
+    };
+    // Solvers parsing the input once and taking parameters: solve_partN(&$parsed, params)
+    ($struct_name:ident, $year:literal, $day:literal, parsed: $parsed:ty, params: [$($name:ident = $default:literal),+ $(,)?]) => {
+        $crate::make_day!(@day $struct_name, $year, $day, false);
+
+        impl $crate::aoc_day::ParseOnce for $struct_name {
+            type Parsed = $parsed;
+
+            fn parse(input: &$crate::PuzzleInput) -> Result<$parsed, $crate::AoCError> {
+                parse(input)
+            }
+
+            fn part1(
+                parsed: &$parsed,
+                params: &$crate::Params,
+                _io: &mut $crate::IoContext,
+            ) -> Result<AoCResult, $crate::AoCError> {
+                solve_part1(parsed, params)
+            }
+
+            fn part2(
+                parsed: &$parsed,
+                params: &$crate::Params,
+                _io: &mut $crate::IoContext,
+            ) -> Result<AoCResult, $crate::AoCError> {
+                solve_part2(parsed, params)
+            }
+        }
+
+        $crate::make_day!(@parse_once $struct_name, $day, [$($name = $default),+]);
+    };
+    (@parse_once $struct_name:ident, $day:literal $(, [$($name:ident = $default:literal),+])?) => {
+        impl AoCDay for $struct_name {
+            fn day(&self) -> u8 {
+                $day
+            }
+
+            $(
+                fn params(&self) -> &'static [$crate::params::ParamInfo] {
+                    &[$($crate::params::ParamInfo { name: stringify!($name), default: $default }),+]
+                }
+            )?
+
+            fn run_part1(
+                &self,
+                input: &$crate::PuzzleInput,
+                params: &$crate::Params,
+                io: &mut $crate::IoContext,
+            ) -> Result<AoCResult, $crate::AoCError> {
+                let params = params.with_defaults(self.params())?;
+                <Self as $crate::aoc_day::ParseOnce>::part1(&<Self as $crate::aoc_day::ParseOnce>::parse(input)?, &params, io)
+            }
+
+            fn run_part2(
+                &self,
+                input: &$crate::PuzzleInput,
+                params: &$crate::Params,
+                io: &mut $crate::IoContext,
+            ) -> Result<AoCResult, $crate::AoCError> {
+                let params = params.with_defaults(self.params())?;
+                <Self as $crate::aoc_day::ParseOnce>::part2(&<Self as $crate::aoc_day::ParseOnce>::parse(input)?, &params, io)
+            }
+
+            fn parse(&self, input: &$crate::PuzzleInput) -> Result<$crate::aoc_day::Parsed, $crate::AoCError> {
+                Ok($crate::aoc_day::Parsed::new(<Self as $crate::aoc_day::ParseOnce>::parse(input)?))
+            }
+
+            fn solve_parsed(
+                &self,
+                part: u8,
+                parsed: &$crate::aoc_day::Parsed,
+                params: &$crate::Params,
+                io: &mut $crate::IoContext,
+            ) -> Result<AoCResult, $crate::AoCError> {
+                let params = params.with_defaults(self.params())?;
+                match part {
+                    1 => <Self as $crate::aoc_day::ParseOnce>::part1(parsed.get(), &params, io),
+                    2 => <Self as $crate::aoc_day::ParseOnce>::part2(parsed.get(), &params, io),
+                    _ => Err($crate::AoCError::InvalidPart($day, part)),
+                }
+            }
+        }
+    };
+    // Solvers with parameters: solve_partN(input, params), e.g. `params: [steps = "1000"]`
+    ($struct_name:ident, $year:literal, $day:literal, params: [$($name:ident = $default:literal),+ $(,)?]) => {
+        $crate::make_day!(@day $struct_name, $year, $day, false);
+
+        impl AoCDay for $struct_name {
+            fn day(&self) -> u8 {
+                $day
+            }
+
+            fn params(&self) -> &'static [$crate::params::ParamInfo] {
+                &[$($crate::params::ParamInfo { name: stringify!($name), default: $default }),+]
+            }
+
+            fn run_part1(
+                &self,
+                input: &$crate::PuzzleInput,
+                params: &$crate::Params,
+                _io: &mut $crate::IoContext,
+            ) -> Result<AoCResult, $crate::AoCError> {
+                solve_part1(input, &params.with_defaults(self.params())?)
+            }
+
+            fn run_part2(
+                &self,
+                input: &$crate::PuzzleInput,
+                params: &$crate::Params,
+                _io: &mut $crate::IoContext,
+            ) -> Result<AoCResult, $crate::AoCError> {
+                solve_part2(input, &params.with_defaults(self.params())?)
+            }
+        }
+    };
+    (@day $struct_name:ident, $year:literal, $day:literal, $interactive:literal) => {
+        use $crate::AoCDay;
+        pub struct $struct_name {}
+
+        $crate::inventory::submit! {
+            $crate::registry::DayRegistration {
+                info: $crate::aoc_year::DayInfo {
+                    year: $year,
+                    day: $day,
+                    interactive: $interactive,
+                },
+                solver: &$struct_name {},
+            }
+        }
+    };
 }
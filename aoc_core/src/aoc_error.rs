@@ -0,0 +1,66 @@
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AoCError {
+    // Input could not be parsed (line and column are 1-based)
+    ParseError { line: usize, column: usize, message: String },
+    NoSolution,
+    // Puzzle input file (given by its path) does not exist
+    MissingInput(String),
+    // Puzzle input exists but could not be read (path and reason)
+    UnreadableInput(String),
+    InvalidParameter(String),
+    InvalidDay(u8),
+    InvalidPart(u8, u8),
+    // Answer is not of the requested type
+    InvalidResult(String),
+    // Solver was stopped by the runner after the time limit
+    Timeout(Duration),
+    // Solver panicked (with the panic message)
+    Panicked(String),
+}
+
+impl AoCError {
+    pub fn parse_error(line: usize, column: usize, message: impl Into<String>) -> Self {
+        AoCError::ParseError { line, column, message: message.into() }
+    }
+}
+
+impl fmt::Display for AoCError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self {
+            AoCError::ParseError { line, column, message } => {
+                write!(f, "Parse error at line {}, column {}: {}", line, column, message)
+            }
+            AoCError::NoSolution => write!(f, "No solution found"),
+            AoCError::MissingInput(path) => write!(f, "Input file not found: {}", path),
+            AoCError::UnreadableInput(msg) => write!(f, "Input could not be read: {}", msg),
+            AoCError::InvalidParameter(msg) => write!(f, "Invalid parameter: {}", msg),
+            AoCError::InvalidDay(day) => write!(f, "Invalid argument: no day {}", day),
+            AoCError::InvalidPart(day, part) => write!(f, "Invalid argument: no part {} on day {}", part, day),
+            AoCError::InvalidResult(msg) => write!(f, "Invalid result: {}", msg),
+            AoCError::Timeout(limit) => write!(f, "Timed out after {:?}", limit),
+            AoCError::Panicked(msg) => write!(f, "Panicked: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for AoCError {}
+
+// Parses a value from the puzzle input, reporting the given position on failure
+pub fn parse_at<T>(value: &str, line: usize, column: usize) -> Result<T, AoCError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    value
+        .parse()
+        .map_err(|err| AoCError::parse_error(line, column, format!("'{}': {}", value, err)))
+}
+
+// 1-based column at which `part` starts inside `line` (`part` must be a subslice of `line`)
+pub fn column_of(line: &str, part: &str) -> usize {
+    (part.as_ptr() as usize).saturating_sub(line.as_ptr() as usize) + 1
+}
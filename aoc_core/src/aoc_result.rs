@@ -1,40 +1,182 @@
+use crate::aoc_error::AoCError;
 use std::fmt;
 
 #[allow(dead_code)]
+#[derive(Debug, Clone)]
 pub enum AoCResult {
     Num(u64),
+    // Signed answer, e.g. a value read from an Intcode program
+    Int(i64),
+    // Answer needing 128-bit arithmetic
+    Wide(i128),
     Str(String),
+    // Multi-line answer such as a rendered image, one entry per line
+    Grid(Vec<String>),
     PrintedToConsole,
     NotImplemented,
-    InvalidDayErr(u8),
-    InvalidPartErr(u8, u8),
+}
+
+impl AoCResult {
+    // Numeric value of the answer, independent of the numeric variant used
+    pub fn as_i128(&self) -> Option<i128> {
+        match self {
+            AoCResult::Num(n) => Some(*n as i128),
+            AoCResult::Int(n) => Some(*n as i128),
+            AoCResult::Wide(n) => Some(*n),
+            _ => None,
+        }
+    }
+
+    // Type of the answer as shown in reports
+    pub fn kind(&self) -> &'static str {
+        match self {
+            AoCResult::Num(_) | AoCResult::Int(_) | AoCResult::Wide(_) => "number",
+            AoCResult::Str(_) => "string",
+            AoCResult::Grid(_) => "grid",
+            AoCResult::PrintedToConsole => "console",
+            AoCResult::NotImplemented => "not implemented",
+        }
+    }
 }
 
 impl std::fmt::Display for AoCResult {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match &self {
             AoCResult::Num(n) => write!(f, "{}", n),
+            AoCResult::Int(n) => write!(f, "{}", n),
+            AoCResult::Wide(n) => write!(f, "{}", n),
             AoCResult::Str(s) => write!(f, "{}", s),
-            AoCResult::PrintedToConsole => write!(f, "{}", "Read answer from console output"),
+            AoCResult::Grid(lines) => write!(f, "{}", lines.join("\n")),
+            AoCResult::PrintedToConsole => write!(f, "Read answer from console output"),
             AoCResult::NotImplemented => write!(f, "Not implemented"),
-            AoCResult::InvalidDayErr(day) => write!(f, "Invalid argument: no day {}", day),
-            AoCResult::InvalidPartErr(day, part) => write!(f, "Invalid argumuent: no part {} on day {}", part, day)
         }
     }
 }
 
-impl From<AoCResult> for u64 {
-    fn from(res: AoCResult) -> Self {
-        if let AoCResult::Num(n) = res { n } else { 0 }
+// Numeric answers are equal if their values are, regardless of the variant
+impl PartialEq for AoCResult {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (AoCResult::Str(a), AoCResult::Str(b)) => a == b,
+            (AoCResult::Grid(a), AoCResult::Grid(b)) => a == b,
+            (AoCResult::PrintedToConsole, AoCResult::PrintedToConsole) => true,
+            (AoCResult::NotImplemented, AoCResult::NotImplemented) => true,
+            _ => self.as_i128().is_some() && self.as_i128() == other.as_i128(),
+        }
+    }
+}
+
+impl Eq for AoCResult {}
+
+impl From<u64> for AoCResult {
+    fn from(value: u64) -> Self {
+        AoCResult::Num(value)
+    }
+}
+
+impl From<usize> for AoCResult {
+    fn from(value: usize) -> Self {
+        AoCResult::Num(value as u64)
+    }
+}
+
+impl From<i64> for AoCResult {
+    fn from(value: i64) -> Self {
+        AoCResult::Int(value)
+    }
+}
+
+impl From<i128> for AoCResult {
+    fn from(value: i128) -> Self {
+        AoCResult::Wide(value)
+    }
+}
+
+impl From<String> for AoCResult {
+    fn from(value: String) -> Self {
+        AoCResult::Str(value)
+    }
+}
+
+impl From<&str> for AoCResult {
+    fn from(value: &str) -> Self {
+        AoCResult::Str(value.to_string())
     }
 }
 
-impl From<AoCResult> for String {
-    fn from(res: AoCResult) -> Self {
-        if let AoCResult::Str(s) = res {
-            s
-        } else {
-            String::from("")
+impl From<Vec<String>> for AoCResult {
+    fn from(value: Vec<String>) -> Self {
+        AoCResult::Grid(value)
+    }
+}
+
+fn invalid_result(res: &AoCResult, expected: &str) -> AoCError {
+    AoCError::InvalidResult(format!("expected {}, got '{}'", expected, res))
+}
+
+impl TryFrom<AoCResult> for u64 {
+    type Error = AoCError;
+
+    fn try_from(res: AoCResult) -> Result<Self, Self::Error> {
+        res.as_i128()
+            .and_then(|n| u64::try_from(n).ok())
+            .ok_or_else(|| invalid_result(&res, "unsigned 64-bit number"))
+    }
+}
+
+impl TryFrom<AoCResult> for i64 {
+    type Error = AoCError;
+
+    fn try_from(res: AoCResult) -> Result<Self, Self::Error> {
+        res.as_i128()
+            .and_then(|n| i64::try_from(n).ok())
+            .ok_or_else(|| invalid_result(&res, "signed 64-bit number"))
+    }
+}
+
+impl TryFrom<AoCResult> for i128 {
+    type Error = AoCError;
+
+    fn try_from(res: AoCResult) -> Result<Self, Self::Error> {
+        res.as_i128().ok_or_else(|| invalid_result(&res, "number"))
+    }
+}
+
+impl TryFrom<AoCResult> for String {
+    type Error = AoCError;
+
+    fn try_from(res: AoCResult) -> Result<Self, Self::Error> {
+        match res {
+            AoCResult::Str(s) => Ok(s),
+            _ => Err(invalid_result(&res, "string")),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numeric_equality() {
+        assert_eq!(AoCResult::Num(42), AoCResult::Int(42));
+        assert_eq!(AoCResult::Wide(42), AoCResult::Num(42));
+        assert_ne!(AoCResult::Int(-1), AoCResult::Num(u64::MAX));
+        assert_ne!(AoCResult::Num(42), AoCResult::Str("42".to_string()));
+    }
+
+    #[test]
+    fn conversions_do_not_wrap() {
+        assert_eq!(u64::try_from(AoCResult::Int(7)), Ok(7));
+        assert!(u64::try_from(AoCResult::Int(-7)).is_err());
+        assert!(i64::try_from(AoCResult::Wide(i128::MAX)).is_err());
+        assert!(u64::try_from(AoCResult::NotImplemented).is_err());
+        assert!(String::try_from(AoCResult::Num(1)).is_err());
+    }
+
+    #[test]
+    fn display_grid() {
+        let grid = AoCResult::from(vec!["#..#".to_string(), ".##.".to_string()]);
+        assert_eq!(grid.to_string(), "#..#\n.##.");
+    }
+}
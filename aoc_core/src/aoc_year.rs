@@ -0,0 +1,74 @@
+use crate::aoc_day::AoCDay;
+use crate::aoc_error::AoCError;
+use crate::aoc_result::AoCResult;
+use crate::io_context::IoContext;
+use crate::params::Params;
+use crate::puzzle_input::PuzzleInput;
+use crate::registry;
+
+// Metadata of a registered day
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DayInfo {
+    pub year: u16,
+    pub day: u8,
+    // Day needs user input while running and cannot be solved unattended
+    pub interactive: bool,
+}
+
+pub trait AoCYear: Sync {
+    fn year(&self) -> u16;
+
+    // Implemented days in ascending order
+    fn days(&self) -> Vec<u8> {
+        registry::day_registrations(self.year())
+            .map(|r| r.info.day)
+            .collect()
+    }
+
+    fn day_info(&self, day: u8) -> Option<DayInfo> {
+        registry::day_registrations(self.year())
+            .find(|r| r.info.day == day)
+            .map(|r| r.info)
+    }
+
+    fn get_day(&self, day: u8) -> Option<&'static dyn AoCDay> {
+        registry::day_registrations(self.year())
+            .find(|r| r.info.day == day)
+            .map(|r| r.solver)
+    }
+
+    fn solve(
+        &self,
+        day: u8,
+        part: u8,
+        input: &PuzzleInput,
+        params: &Params,
+        io: &mut IoContext,
+    ) -> Result<AoCResult, AoCError> {
+        let solver = self.get_day(day).ok_or(AoCError::InvalidDay(day))?;
+        match part {
+            1 => solver.run_part1(input, params, io),
+            2 => solver.run_part2(input, params, io),
+            _ => Err(AoCError::InvalidPart(day, part)),
+        }
+    }
+}
+
+#[macro_export]
+macro_rules! make_year {
+    ($struct_name:ident, $year:literal) => {
+        pub struct $struct_name {}
+
+        impl $crate::AoCYear for $struct_name {
+            fn year(&self) -> u16 {
+                $year
+            }
+        }
+
+        $crate::inventory::submit! {
+            $crate::registry::YearRegistration {
+                year: &$struct_name {},
+            }
+        }
+    };
+}
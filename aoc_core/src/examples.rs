@@ -0,0 +1,166 @@
+use crate::aoc_error::AoCError;
+use crate::io_context::IoContext;
+use crate::params::Params;
+use crate::puzzle_input::PuzzleInput;
+use crate::registry;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// Puzzle example stored as `examples/<year>/dayNN/<name>.txt`. The file starts with a header
+// giving the expected answers, separated from the puzzle input by a `---` line:
+//
+//     part1: 42
+//     part2: 4
+//     param: steps=10
+//     ignore: reason the example is skipped
+//     ---
+//     <puzzle input>
+//
+// All header keys are optional, but at least one answer is needed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Example {
+    // Expected answers by part
+    pub answers: Vec<(u8, String)>,
+    pub params: Params,
+    pub ignore: Option<String>,
+    pub input: PuzzleInput,
+}
+
+const SEPARATOR: &str = "---";
+
+impl Example {
+    pub fn parse(text: &str) -> Result<Example, AoCError> {
+        // Line break after the separator is optional if the input is empty
+        let text = PuzzleInput::with_trailing_newline(text).to_string() + "\n";
+        let (header, input) = match text.strip_prefix(SEPARATOR).and_then(|rest| rest.strip_prefix('\n')) {
+            Some(input) => ("", input),
+            None => text.split_once(&format!("\n{}\n", SEPARATOR)).ok_or_else(|| {
+                AoCError::parse_error(1, 1, format!("expected a '{}' line after the header", SEPARATOR))
+            })?,
+        };
+
+        let mut example = Example {
+            answers: vec![],
+            params: Params::new(),
+            ignore: None,
+            input: PuzzleInput::new(input),
+        };
+
+        let header = PuzzleInput::new(header);
+        for line in header.lines().filter(|l| !l.trim().is_empty()) {
+            let (key, value) = line.split_once_checked(":")?;
+            let value = value.trim();
+            match key.trim().text {
+                "part1" => example.answers.push((1, value.text.to_string())),
+                "part2" => example.answers.push((2, value.text.to_string())),
+                "param" => {
+                    let (name, param) = Params::parse_override(value.text).map_err(|_| value.error("expected 'key=value'"))?;
+                    example.params = example.params.with(&name, param);
+                }
+                "ignore" => example.ignore = Some(value.text.to_string()),
+                other => {
+                    return Err(key.error(format!("unknown key '{}', expected part1, part2, param or ignore", other)));
+                }
+            }
+        }
+
+        Ok(example)
+    }
+}
+
+// Solves the example with the registered day and panics if an answer does not match
+pub fn check(year: u16, day: u8, name: &str, text: &str) {
+    let example = Example::parse(text).unwrap_or_else(|err| panic!("{}: {}", name, err));
+    assert!(!example.answers.is_empty(), "{}: no expected answer, add a 'part1:' or 'part2:' line", name);
+
+    let solver = registry::get_year(year).unwrap_or_else(|| panic!("year {} is not registered", year));
+    for (part, expected) in &example.answers {
+        match solver.solve(day, *part, &example.input, &example.params, &mut IoContext::silent()) {
+            Ok(result) => assert_eq!(&result.to_string(), expected, "{} (part {})", name, part),
+            Err(err) => panic!("{} (part {}): {}", name, part, err),
+        }
+    }
+}
+
+// To be called from the build script of a year crate: writes one test per example file found in
+// `dir` to `$OUT_DIR/example_tests.rs`, which the crate includes in its tests
+pub fn generate_tests(year: u16, dir: impl AsRef<Path>) {
+    let dir = dir.as_ref();
+    println!("cargo:rerun-if-changed={}", dir.display());
+
+    let mut tests = String::new();
+    for (day, path) in example_files(dir) {
+        let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+        let test_name: String = format!("day{:02}_{}", day, stem)
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_lowercase() } else { '_' })
+            .collect();
+        let name = format!("day{:02}/{}.txt", day, stem);
+        let path = fs::canonicalize(&path).unwrap_or(path);
+
+        // Skipping the test is decided at build time, invalid files are reported by the test itself
+        let ignore = fs::read_to_string(&path)
+            .ok()
+            .and_then(|text| Example::parse(&text).ok())
+            .and_then(|example| example.ignore)
+            .map(|reason| format!("#[ignore = {:?}]\n", reason))
+            .unwrap_or_default();
+
+        tests += &format!(
+            "#[test]\n{}fn {}() {{\n    aoc_core::examples::check({}, {}, {:?}, include_str!({:?}));\n}}\n\n",
+            ignore, test_name, year, day, name, path
+        );
+    }
+
+    let out_dir = env::var("OUT_DIR").expect("generate_tests must be called from a build script");
+    fs::write(Path::new(&out_dir).join("example_tests.rs"), tests).expect("could not write example tests");
+}
+
+// Text files in the `dayNN` directories, sorted by day and name
+fn example_files(dir: &Path) -> Vec<(u8, PathBuf)> {
+    let mut files = vec![];
+    for entry in fs::read_dir(dir).into_iter().flatten().flatten() {
+        let file_name = entry.file_name();
+        let Some(day) = file_name.to_str().and_then(|n| n.strip_prefix("day")).and_then(|d| d.parse().ok()) else {
+            continue;
+        };
+
+        for file in fs::read_dir(entry.path()).into_iter().flatten().flatten() {
+            let path = file.path();
+            if path.extension().is_some_and(|ext| ext == "txt") {
+                files.push((day, path));
+            }
+        }
+    }
+
+    files.sort();
+    files
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_example() {
+        let example = Example::parse("part1: 42\r\nparam: steps = 10\r\n---\r\nCOM)B\r\nB)C\r\n").unwrap();
+        assert_eq!(example.answers, vec![(1, "42".to_string())]);
+        assert_eq!(example.params, Params::from([("steps", "10")]));
+        assert_eq!(example.ignore, None);
+        assert_eq!(example.input, PuzzleInput::new("COM)B\nB)C"));
+    }
+
+    #[test]
+    fn parse_example_without_header() {
+        let example = Example::parse("---\n1\n2").unwrap();
+        assert!(example.answers.is_empty());
+        assert_eq!(example.input.as_str(), "1\n2");
+    }
+
+    #[test]
+    fn invalid_header() {
+        assert!(matches!(Example::parse("part1: 42\n1\n2"), Err(AoCError::ParseError { line: 1, .. })));
+        assert!(matches!(Example::parse("part1: 1\npart3: 2\n---\n"), Err(AoCError::ParseError { line: 2, column: 1, .. })));
+    }
+}
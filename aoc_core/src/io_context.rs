@@ -0,0 +1,200 @@
+use std::io::{self, BufRead, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+
+// Single picture of an animation, e.g. the current state of a game screen
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub lines: Vec<String>,
+    pub caption: String,
+}
+
+impl Frame {
+    pub fn new(lines: Vec<String>, caption: impl Into<String>) -> Self {
+        Frame { lines, caption: caption.into() }
+    }
+
+    // Splits a row-major character buffer into lines of the given width
+    pub fn from_chars(chars: &[char], width: usize, caption: impl Into<String>) -> Self {
+        Frame::new(chars.chunks(width.max(1)).map(|line| line.iter().collect()).collect(), caption)
+    }
+}
+
+// Receives the frames a solver produces while running, see `visualize` for the sinks
+pub trait FrameSink {
+    fn show(&mut self, frame: &Frame);
+
+    // Called once the solver is done, e.g. to write buffered frames or report write errors
+    fn finish(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<T: FrameSink + ?Sized> FrameSink for &mut T {
+    fn show(&mut self, frame: &Frame) {
+        (**self).show(frame);
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        (**self).finish()
+    }
+}
+
+impl<T: FrameSink + ?Sized> FrameSink for Box<T> {
+    fn show(&mut self, frame: &Frame) {
+        (**self).show(frame);
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        (**self).finish()
+    }
+}
+
+// Sink that can be taken away from a solver, frames shown afterwards are dropped
+impl<T: FrameSink> FrameSink for Option<T> {
+    fn show(&mut self, frame: &Frame) {
+        if let Some(sink) = self {
+            sink.show(frame);
+        }
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        self.as_mut().map_or(Ok(()), FrameSink::finish)
+    }
+}
+
+// Sink that is still available to the caller after the solver has been moved to another thread
+impl<T: FrameSink + ?Sized> FrameSink for Arc<Mutex<T>> {
+    fn show(&mut self, frame: &Frame) {
+        self.lock().unwrap_or_else(PoisonError::into_inner).show(frame);
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        self.lock().unwrap_or_else(PoisonError::into_inner).finish()
+    }
+}
+
+// Collecting frames, e.g. for tests
+impl FrameSink for Vec<Frame> {
+    fn show(&mut self, frame: &Frame) {
+        self.push(frame.clone());
+    }
+}
+
+// Everything a solver may use to interact with the outside world besides its puzzle input
+pub struct IoContext<'a> {
+    input: Box<dyn BufRead + 'a>,
+    output: Box<dyn Write + 'a>,
+    frames: Option<Box<dyn FrameSink + 'a>>,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl<'a> IoContext<'a> {
+    pub fn new(input: impl BufRead + 'a, output: impl Write + 'a) -> Self {
+        IoContext { input: Box::new(input), output: Box::new(output), frames: None, cancelled: Arc::default() }
+    }
+
+    // Reads from stdin and writes to stdout
+    pub fn terminal() -> IoContext<'static> {
+        IoContext::new(io::stdin().lock(), io::stdout())
+    }
+
+    // No input available and all output discarded
+    pub fn silent() -> IoContext<'static> {
+        IoContext::new(io::empty(), io::sink())
+    }
+
+    pub fn with_frames(mut self, sink: impl FrameSink + 'a) -> Self {
+        self.frames = Some(Box::new(sink));
+        self
+    }
+
+    // Once `flag` is set (e.g. because the solver timed out), the solver gets no more input and
+    // its output and frames are dropped
+    pub fn cancelled_by(mut self, flag: Arc<AtomicBool>) -> Self {
+        self.cancelled = flag;
+        self
+    }
+
+    // Whether nobody waits for the result anymore, so that long running solvers can stop early
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    // Next line of input without its line break, None if the input is exhausted
+    pub fn read_line(&mut self) -> Option<String> {
+        if self.is_cancelled() {
+            return None;
+        }
+        let mut line = String::new();
+        match self.input.read_line(&mut line) {
+            Ok(0) | Err(_) => None,
+            Ok(_) => Some(line.trim_end_matches(['\r', '\n']).to_string()),
+        }
+    }
+
+    pub fn write(&mut self, text: &str) {
+        if self.is_cancelled() {
+            return;
+        }
+        // Output is informational only, so a closed output must not abort the solver
+        let _ = self.output.write_all(text.as_bytes()).and_then(|_| self.output.flush());
+    }
+
+    // Whether anybody is watching, so that solvers can skip building frames otherwise
+    pub fn wants_frames(&self) -> bool {
+        self.frames.is_some() && !self.is_cancelled()
+    }
+
+    pub fn show_frame(&mut self, frame: &Frame) {
+        if self.is_cancelled() {
+            return;
+        }
+        if let Some(sink) = self.frames.as_mut() {
+            sink.show(frame);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scripted_input_and_output() {
+        let mut output = vec![];
+        let mut io = IoContext::new("north\r\ntake map\n".as_bytes(), &mut output);
+        assert_eq!(io.read_line(), Some("north".to_string()));
+        assert_eq!(io.read_line(), Some("take map".to_string()));
+        assert_eq!(io.read_line(), None);
+        io.write("Ok");
+        drop(io);
+        assert_eq!(output, b"Ok");
+    }
+
+    #[test]
+    fn frames() {
+        let mut frames = vec![];
+        let mut io = IoContext::new(io::empty(), io::sink()).with_frames(&mut frames);
+        assert!(io.wants_frames());
+        io.show_frame(&Frame::from_chars(&['#', '.', '.', '#'], 2, "Score: 0"));
+        drop(io);
+        assert_eq!(frames, vec![Frame::new(vec!["#.".to_string(), ".#".to_string()], "Score: 0")]);
+    }
+
+    #[test]
+    fn cancelled() {
+        let (mut output, mut frames) = (vec![], vec![]);
+        let flag = Arc::new(AtomicBool::new(false));
+        let mut io = IoContext::new("north\n".as_bytes(), &mut output)
+            .with_frames(&mut frames)
+            .cancelled_by(Arc::clone(&flag));
+        flag.store(true, Ordering::Relaxed);
+        assert!(io.is_cancelled() && !io.wants_frames());
+        assert_eq!(io.read_line(), None);
+        io.write("Ok");
+        io.show_frame(&Frame::new(vec![], "Score: 0"));
+        drop(io);
+        assert!(output.is_empty() && frames.is_empty());
+    }
+}
@@ -2,5 +2,36 @@
 pub mod aoc_day;
 pub use crate::aoc_day::AoCDay;
 
+pub mod aoc_error;
+pub use crate::aoc_error::AoCError;
+
 pub mod aoc_result;
-pub use crate::aoc_result::AoCResult;
\ No newline at end of file
+pub use crate::aoc_result::AoCResult;
+
+#[macro_use]
+pub mod aoc_year;
+pub use crate::aoc_year::AoCYear;
+
+pub mod examples;
+
+pub mod io_context;
+pub use crate::io_context::IoContext;
+
+#[macro_use]
+pub mod log;
+
+pub mod ocr;
+
+pub mod params;
+pub use crate::params::Params;
+
+pub mod puzzle_input;
+pub use crate::puzzle_input::PuzzleInput;
+
+pub mod registry;
+
+pub mod visualize;
+
+// Re-exported for use in `make_day!` and `make_year!`
+#[doc(hidden)]
+pub use inventory;
@@ -0,0 +1,84 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+
+// Debug output of the solvers, written to stderr so that it does not mix with the answers.
+// Nothing is logged by default, the runner raises the level with -v (debug) or -vv (trace).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    // Short messages about the progress of a solver
+    Debug = 1,
+    // Large dumps, e.g. whole grids
+    Trace = 2,
+}
+
+impl Level {
+    fn label(self) -> &'static str {
+        match self {
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        }
+    }
+}
+
+// 0: logging is off
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(0);
+
+// Sets the level from the number of -v flags (0 turns logging off)
+pub fn set_verbosity(verbosity: u8) {
+    MAX_LEVEL.store(max_level(verbosity), Ordering::Relaxed);
+}
+
+// Messages are only formatted if their level is enabled, so solvers can log in hot loops
+pub fn enabled(level: Level) -> bool {
+    is_enabled(level, MAX_LEVEL.load(Ordering::Relaxed))
+}
+
+fn max_level(verbosity: u8) -> u8 {
+    verbosity.min(Level::Trace as u8)
+}
+
+fn is_enabled(level: Level, max_level: u8) -> bool {
+    level as u8 <= max_level
+}
+
+// Used by `debug!` and `trace!`, each line of a multi-line message gets its own prefix
+#[doc(hidden)]
+pub fn write(level: Level, message: std::fmt::Arguments) {
+    for line in message.to_string().lines() {
+        eprintln!("[{}] {}", level.label(), line);
+    }
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Debug) {
+            $crate::log::write($crate::log::Level::Debug, format_args!($($arg)*));
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Trace) {
+            $crate::log::write($crate::log::Level::Trace, format_args!($($arg)*));
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Tests run in parallel, so they must not change the global level
+    #[test]
+    fn verbosity_levels() {
+        assert!(!is_enabled(Level::Debug, max_level(0)));
+
+        assert!(is_enabled(Level::Debug, max_level(1)));
+        assert!(!is_enabled(Level::Trace, max_level(1)));
+
+        // More flags than levels enable everything
+        assert!(is_enabled(Level::Trace, max_level(5)));
+    }
+}
@@ -0,0 +1,169 @@
+use crate::aoc_result::AoCResult;
+
+// Block letters used by Advent of Code image puzzles, 6 pixels high (4 to 5 wide)
+const FONT_6: [(char, &str); 18] = [
+    ('A', ".##.|#..#|#..#|####|#..#|#..#"),
+    ('B', "###.|#..#|###.|#..#|#..#|###."),
+    ('C', ".##.|#..#|#...|#...|#..#|.##."),
+    ('E', "####|#...|###.|#...|#...|####"),
+    ('F', "####|#...|###.|#...|#...|#..."),
+    ('G', ".##.|#..#|#...|#.##|#..#|.###"),
+    ('H', "#..#|#..#|####|#..#|#..#|#..#"),
+    ('I', ".###|..#.|..#.|..#.|..#.|.###"),
+    ('J', "..##|...#|...#|...#|#..#|.##."),
+    ('K', "#..#|#.#.|##..|#.#.|#.#.|#..#"),
+    ('L', "#...|#...|#...|#...|#...|####"),
+    ('O', ".##.|#..#|#..#|#..#|#..#|.##."),
+    ('P', "###.|#..#|#..#|###.|#...|#..."),
+    ('R', "###.|#..#|#..#|###.|#.#.|#..#"),
+    ('S', ".###|#...|#...|.##.|...#|###."),
+    ('U', "#..#|#..#|#..#|#..#|#..#|.##."),
+    ('Y', "#...#|#...#|.#.#.|..#..|..#..|..#.."),
+    ('Z', "####|...#|..#.|.#..|#...|####"),
+];
+
+// Block letters 10 pixels high (6 wide)
+const FONT_10: [(char, &str); 15] = [
+    ('A', "..##..|.#..#.|#....#|#....#|#....#|######|#....#|#....#|#....#|#....#"),
+    ('B', "#####.|#....#|#....#|#....#|#####.|#....#|#....#|#....#|#....#|#####."),
+    ('C', ".####.|#....#|#.....|#.....|#.....|#.....|#.....|#.....|#....#|.####."),
+    ('E', "######|#.....|#.....|#.....|#####.|#.....|#.....|#.....|#.....|######"),
+    ('F', "######|#.....|#.....|#.....|#####.|#.....|#.....|#.....|#.....|#....."),
+    ('G', ".####.|#....#|#.....|#.....|#.....|#..###|#....#|#....#|#...##|.###.#"),
+    ('H', "#....#|#....#|#....#|#....#|######|#....#|#....#|#....#|#....#|#....#"),
+    ('J', "...###|....#.|....#.|....#.|....#.|....#.|....#.|#...#.|#...#.|.###.."),
+    ('K', "#....#|#...#.|#..#..|#.#...|##....|##....|#.#...|#..#..|#...#.|#....#"),
+    ('L', "#.....|#.....|#.....|#.....|#.....|#.....|#.....|#.....|#.....|######"),
+    ('N', "#....#|##...#|##...#|#.#..#|#.#..#|#..#.#|#..#.#|#...##|#...##|#....#"),
+    ('P', "#####.|#....#|#....#|#....#|#####.|#.....|#.....|#.....|#.....|#....."),
+    ('R', "#####.|#....#|#....#|#....#|#####.|#..#..|#...#.|#...#.|#....#|#....#"),
+    ('X', "#....#|#....#|.#..#.|.#..#.|..##..|..##..|.#..#.|.#..#.|#....#|#....#"),
+    ('Z', "######|.....#|.....#|....#.|...#..|..#...|.#....|#.....|#.....|######"),
+];
+
+// Reads the letters shown in the image, or returns the image as text if it cannot be recognized
+pub fn to_result(pixels: &[Vec<bool>]) -> AoCResult {
+    match recognize(pixels) {
+        Some(letters) => AoCResult::Str(letters),
+        None => AoCResult::Grid(render(pixels)),
+    }
+}
+
+// Recognizes block letters in an image given as rows of pixels (true = lit).
+// Letters need to be separated by at least one empty column.
+pub fn recognize(pixels: &[Vec<bool>]) -> Option<String> {
+    let lit_rows = (0..pixels.len()).filter(|&y| pixels[y].contains(&true)).collect::<Vec<_>>();
+    let rows = &pixels[*lit_rows.first()?..=*lit_rows.last()?];
+    let font: &[(char, &str)] = match rows.len() {
+        6 => &FONT_6,
+        10 => &FONT_10,
+        _ => return None,
+    };
+
+    let width = rows.iter().map(|row| row.len()).max()?;
+    let is_empty_column = |x: usize| rows.iter().all(|row| !row.get(x).copied().unwrap_or(false));
+
+    let mut letters = String::new();
+    let mut x = 0;
+    while x < width {
+        if is_empty_column(x) {
+            x += 1;
+            continue;
+        }
+
+        let start = x;
+        while x < width && !is_empty_column(x) {
+            x += 1;
+        }
+
+        let glyph = rows
+            .iter()
+            .map(|row| (start..x).map(|i| if row.get(i) == Some(&true) { '#' } else { '.' }).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("|");
+
+        letters.push(font.iter().find(|(_, pattern)| trim_columns(pattern) == glyph)?.0);
+    }
+
+    (!letters.is_empty()).then_some(letters)
+}
+
+// Renders the image with '#' for lit and '.' for dark pixels
+pub fn render(pixels: &[Vec<bool>]) -> Vec<String> {
+    pixels
+        .iter()
+        .map(|row| row.iter().map(|&p| if p { '#' } else { '.' }).collect())
+        .collect()
+}
+
+// Removes empty columns left and right of a glyph pattern
+fn trim_columns(pattern: &str) -> String {
+    let rows = pattern.split('|').collect::<Vec<_>>();
+    let lit = |x: usize| rows.iter().any(|row| row.as_bytes()[x] == b'#');
+    let width = rows[0].len();
+    let (Some(start), Some(end)) = ((0..width).find(|&x| lit(x)), (0..width).rfind(|&x| lit(x))) else {
+        return String::new();
+    };
+
+    rows.iter().map(|row| &row[start..=end]).collect::<Vec<_>>().join("|")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(image: &str) -> Vec<Vec<bool>> {
+        image.lines().map(|l| l.chars().map(|c| c == '#').collect()).collect()
+    }
+
+    #[test]
+    fn recognize_font_6() {
+        let image = parse(
+            ".##..###..#..#.####.\n\
+             #..#.#..#.#..#.#....\n\
+             #..#.###..####.###..\n\
+             ####.#..#.#..#.#....\n\
+             #..#.#..#.#..#.#....\n\
+             #..#.###..#..#.####.",
+        );
+        assert_eq!(recognize(&image), Some("ABHE".to_string()));
+    }
+
+    #[test]
+    fn recognize_font_6_with_margin() {
+        let image = parse(
+            "..........\n\
+             .#...#....\n\
+             .#...#....\n\
+             ..#.#.....\n\
+             ...#......\n\
+             ...#......\n\
+             ...#......\n\
+             ..........",
+        );
+        assert_eq!(to_result(&image), AoCResult::Str("Y".to_string()));
+    }
+
+    #[test]
+    fn recognize_font_10() {
+        let image = parse(
+            "#....#..#####.\n\
+             #....#..#....#\n\
+             .#..#...#....#\n\
+             .#..#...#....#\n\
+             ..##....#####.\n\
+             ..##....#.....\n\
+             .#..#...#.....\n\
+             .#..#...#.....\n\
+             #....#..#.....\n\
+             #....#..#.....",
+        );
+        assert_eq!(recognize(&image), Some("XP".to_string()));
+    }
+
+    #[test]
+    fn unknown_image_falls_back_to_rendering() {
+        let image = parse(".#\n#.");
+        assert_eq!(to_result(&image), AoCResult::Grid(vec![".#".to_string(), "#.".to_string()]));
+    }
+}
@@ -0,0 +1,136 @@
+use crate::aoc_error::AoCError;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+// Puzzle constant a day declares (e.g. the number of simulation steps), so that it can be
+// overridden to run the smaller puzzle examples
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParamInfo {
+    pub name: &'static str,
+    pub default: &'static str,
+}
+
+// Parameter values passed to a solver, given as `key=value` strings
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params {
+    values: BTreeMap<String, String>,
+}
+
+impl Params {
+    pub fn new() -> Self {
+        Params::default()
+    }
+
+    pub fn with(mut self, name: &str, value: impl ToString) -> Self {
+        self.values.insert(name.to_string(), value.to_string());
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    // Parses a single `key=value` override
+    pub fn parse_override(value: &str) -> Result<(String, String), AoCError> {
+        value
+            .split_once('=')
+            .map(|(key, value)| (key.trim().to_string(), value.trim().to_string()))
+            .filter(|(key, _)| !key.is_empty())
+            .ok_or_else(|| AoCError::InvalidParameter(format!("expected 'key=value', got '{}'", value)))
+    }
+
+    // Fills in the defaults of the declared parameters, rejecting overrides that are not declared
+    pub fn with_defaults(&self, declared: &[ParamInfo]) -> Result<Params, AoCError> {
+        if let Some(unknown) = self.values.keys().find(|key| !declared.iter().any(|p| &p.name == key)) {
+            let names = declared.iter().map(|p| p.name).collect::<Vec<_>>();
+            return Err(AoCError::InvalidParameter(if names.is_empty() {
+                format!("unknown parameter '{}', this day has no parameters", unknown)
+            } else {
+                format!("unknown parameter '{}', expected one of: {}", unknown, names.join(", "))
+            }));
+        }
+
+        let mut params = self.clone();
+        for p in declared {
+            params.values.entry(p.name.to_string()).or_insert_with(|| p.default.to_string());
+        }
+        Ok(params)
+    }
+
+    pub fn get<T>(&self, name: &str) -> Result<T, AoCError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        let value = self
+            .values
+            .get(name)
+            .ok_or_else(|| AoCError::InvalidParameter(format!("missing parameter '{}'", name)))?;
+        value
+            .parse()
+            .map_err(|err| AoCError::InvalidParameter(format!("{}={}: {}", name, value, err)))
+    }
+
+    // For sizes and counts, which have to be greater than zero
+    pub fn get_positive<T>(&self, name: &str) -> Result<T, AoCError>
+    where
+        T: FromStr + PartialOrd + Default + fmt::Display,
+        T::Err: fmt::Display,
+    {
+        let value: T = self.get(name)?;
+        if value <= T::default() {
+            return Err(AoCError::InvalidParameter(format!("{}={}: must be greater than 0", name, value)));
+        }
+        Ok(value)
+    }
+}
+
+impl<const N: usize> From<[(&str, &str); N]> for Params {
+    fn from(values: [(&str, &str); N]) -> Self {
+        values.into_iter().fold(Params::new(), |params, (name, value)| params.with(name, value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DECLARED: [ParamInfo; 2] = [
+        ParamInfo { name: "width", default: "25" },
+        ParamInfo { name: "height", default: "6" },
+    ];
+
+    #[test]
+    fn defaults_and_overrides() {
+        let params = Params::from([("width", "3")]).with_defaults(&DECLARED).unwrap();
+        assert_eq!(params.get::<usize>("width"), Ok(3));
+        assert_eq!(params.get::<usize>("height"), Ok(6));
+    }
+
+    #[test]
+    fn unknown_parameter() {
+        let err = Params::from([("steps", "10")]).with_defaults(&DECLARED).unwrap_err();
+        assert_eq!(err, AoCError::InvalidParameter("unknown parameter 'steps', expected one of: width, height".to_string()));
+    }
+
+    #[test]
+    fn invalid_value() {
+        let params = Params::from([("width", "-1")]);
+        assert!(matches!(params.get::<usize>("width"), Err(AoCError::InvalidParameter(_))));
+    }
+
+    #[test]
+    fn positive_value() {
+        let params = Params::from([("width", "0"), ("height", "-2"), ("depth", "3")]);
+        assert!(matches!(params.get_positive::<usize>("width"), Err(AoCError::InvalidParameter(_))));
+        assert!(matches!(params.get_positive::<i64>("height"), Err(AoCError::InvalidParameter(_))));
+        assert_eq!(params.get_positive::<i64>("depth"), Ok(3));
+    }
+
+    #[test]
+    fn parse_override() {
+        assert_eq!(Params::parse_override("steps=10"), Ok(("steps".to_string(), "10".to_string())));
+        assert!(Params::parse_override("steps").is_err());
+    }
+}
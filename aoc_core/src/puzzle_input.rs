@@ -0,0 +1,270 @@
+use crate::aoc_error::{column_of, parse_at, AoCError};
+use std::fmt;
+use std::ops::Deref;
+use std::str::FromStr;
+
+// Puzzle input with normalized line endings, so that solvers see the same text
+// regardless of whether the input file was saved with CRLF or LF line endings
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PuzzleInput {
+    text: String,
+}
+
+impl PuzzleInput {
+    // Converts all line endings to LF and removes trailing line breaks
+    pub fn new(raw: &str) -> Self {
+        let mut input = Self::with_trailing_newline(raw);
+        input.text.truncate(input.text.trim_end_matches('\n').len());
+        input
+    }
+
+    // Converts all line endings to LF, but keeps trailing line breaks
+    pub fn with_trailing_newline(raw: &str) -> Self {
+        PuzzleInput { text: raw.replace("\r\n", "\n").replace('\r', "\n") }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    // Lines of the input together with their line numbers
+    pub fn lines(&self) -> impl Iterator<Item = Line<'_>> {
+        self.text.lines().enumerate().map(|(i, text)| Line { number: i + 1, column: 1, text })
+    }
+
+    // Groups of lines separated by blank lines
+    pub fn paragraphs(&self) -> impl Iterator<Item = Vec<Line<'_>>> {
+        let mut lines = self.lines().peekable();
+        std::iter::from_fn(move || {
+            while lines.next_if(|l| l.trim().is_empty()).is_some() {}
+            let paragraph = std::iter::from_fn(|| lines.next_if(|l| !l.trim().is_empty())).collect::<Vec<_>>();
+            (!paragraph.is_empty()).then_some(paragraph)
+        })
+    }
+
+    // All (signed) integers contained in the input, ignoring any other characters
+    pub fn ints<T>(&self) -> Result<Vec<T>, AoCError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        flatten(self.lines().map(|l| l.ints()))
+    }
+
+    // Comma separated integers, e.g. an Intcode program
+    pub fn csv_ints<T>(&self) -> Result<Vec<T>, AoCError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        flatten(self.lines().filter(|l| !l.trim().is_empty()).map(|l| l.csv_ints()))
+    }
+
+    // Rectangular grid of characters, indexed by [y][x]
+    pub fn grid(&self) -> Result<Vec<Vec<char>>, AoCError> {
+        let grid = self.lines().map(|l| l.chars().collect::<Vec<_>>()).collect::<Vec<_>>();
+        if let Some(row) = grid.iter().position(|row| row.len() != grid[0].len()) {
+            return Err(AoCError::parse_error(
+                row + 1,
+                1,
+                format!("expected {} columns, found {}", grid[0].len(), grid[row].len()),
+            ));
+        }
+
+        Ok(grid)
+    }
+
+    // Splits the whole input (e.g. into sections), failing at the end of the input if `delimiter` is missing
+    pub fn split_once_checked(&self, delimiter: &str) -> Result<(&str, &str), AoCError> {
+        self.text.split_once(delimiter).ok_or_else(|| {
+            let last = self.lines().last().unwrap_or(Line { number: 1, column: 1, text: "" });
+            AoCError::parse_error(last.number, last.len() + 1, format!("expected '{}'", delimiter.escape_debug()))
+        })
+    }
+}
+
+impl Deref for PuzzleInput {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.text
+    }
+}
+
+impl fmt::Display for PuzzleInput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+impl From<&str> for PuzzleInput {
+    fn from(value: &str) -> Self {
+        PuzzleInput::new(value)
+    }
+}
+
+impl From<String> for PuzzleInput {
+    fn from(value: String) -> Self {
+        PuzzleInput::new(&value)
+    }
+}
+
+// Part of a single line of the puzzle input, remembering where it is located so that
+// parse errors can point to the exact position
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    // 1-based line number
+    pub number: usize,
+    // 1-based column at which `text` starts
+    pub column: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    // Narrows the line down to `part`, which must be a subslice of this line
+    pub fn slice(&self, part: &'a str) -> Line<'a> {
+        Line { number: self.number, column: self.column + column_of(self.text, part) - 1, text: part }
+    }
+
+    pub fn trim(&self) -> Line<'a> {
+        self.slice(self.text.trim())
+    }
+
+    pub fn split(&self, delimiter: &'a str) -> impl Iterator<Item = Line<'a>> + use<'a> {
+        let line = *self;
+        self.text.split(delimiter).map(move |part| line.slice(part))
+    }
+
+    pub fn split_once_checked(&self, delimiter: &str) -> Result<(Line<'a>, Line<'a>), AoCError> {
+        self.text
+            .split_once(delimiter)
+            .map(|(left, right)| (self.slice(left), self.slice(right)))
+            .ok_or_else(|| self.error(format!("expected '{}'", delimiter)))
+    }
+
+    // Parses the whole (trimmed) line
+    pub fn parse<T>(&self) -> Result<T, AoCError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        let value = self.trim();
+        parse_at(value.text, value.number, value.column)
+    }
+
+    // All (signed) integers contained in the line, ignoring any other characters
+    pub fn ints<T>(&self) -> Result<Vec<T>, AoCError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        let bytes = self.text.as_bytes();
+        let mut ints = vec![];
+        let mut i = 0;
+        while i < bytes.len() {
+            let is_sign = bytes[i] == b'-' && bytes.get(i + 1).is_some_and(u8::is_ascii_digit);
+            if is_sign || bytes[i].is_ascii_digit() {
+                let start = i;
+                i += 1;
+                while i < bytes.len() && bytes[i].is_ascii_digit() {
+                    i += 1;
+                }
+                ints.push(self.slice(&self.text[start..i]).parse()?);
+            } else {
+                i += 1;
+            }
+        }
+
+        Ok(ints)
+    }
+
+    pub fn csv_ints<T>(&self) -> Result<Vec<T>, AoCError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        self.split(",").map(|value| value.parse()).collect()
+    }
+
+    // Parse error pointing to the start of this line (part)
+    pub fn error(&self, message: impl Into<String>) -> AoCError {
+        AoCError::parse_error(self.number, self.column, message)
+    }
+}
+
+impl Deref for Line<'_> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.text
+    }
+}
+
+fn flatten<T>(results: impl Iterator<Item = Result<Vec<T>, AoCError>>) -> Result<Vec<T>, AoCError> {
+    let mut values = vec![];
+    for result in results {
+        values.extend(result?);
+    }
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crlf_and_lf_are_equal() {
+        let crlf = PuzzleInput::from("#.#\r\n.#.\r\n\r\n123\r\n");
+        let lf = PuzzleInput::from("#.#\n.#.\n\n123\n");
+        assert_eq!(crlf, lf);
+        assert_eq!(crlf.as_str(), "#.#\n.#.\n\n123");
+    }
+
+    #[test]
+    fn paragraphs() {
+        let input = PuzzleInput::from("1\n2\n\n\n3\n\n4");
+        let paragraphs = input.paragraphs().map(|p| p.iter().map(|l| l.number).collect::<Vec<_>>()).collect::<Vec<_>>();
+        assert_eq!(paragraphs, vec![vec![1, 2], vec![5], vec![7]]);
+    }
+
+    #[test]
+    fn ints() {
+        let input = PuzzleInput::from("<x=-1, y=0, z=2>\n<x=12, y=-10, z=-7>");
+        assert_eq!(input.ints::<i64>().unwrap(), vec![-1, 0, 2, 12, -10, -7]);
+    }
+
+    #[test]
+    fn ints_overflow_reports_position() {
+        let input = PuzzleInput::from("1 2\nx=300");
+        assert!(matches!(input.ints::<u8>(), Err(AoCError::ParseError { line: 2, column: 3, .. })));
+    }
+
+    #[test]
+    fn csv_ints() {
+        let input = PuzzleInput::from("1,0, -3,99\n");
+        assert_eq!(input.csv_ints::<i64>().unwrap(), vec![1, 0, -3, 99]);
+        let input = PuzzleInput::from("1,2,x");
+        assert!(matches!(input.csv_ints::<i64>(), Err(AoCError::ParseError { line: 1, column: 5, .. })));
+    }
+
+    #[test]
+    fn grid_must_be_rectangular() {
+        assert_eq!(PuzzleInput::from("#.\n.#").grid().unwrap(), vec![vec!['#', '.'], vec!['.', '#']]);
+        assert!(matches!(PuzzleInput::from("#.\n.#\n#").grid(), Err(AoCError::ParseError { line: 3, .. })));
+    }
+
+    #[test]
+    fn split_once_checked() {
+        let input = PuzzleInput::from("10 ORE => 10 A\n7 A 1 B");
+        let lines = input.lines().collect::<Vec<_>>();
+        let (ingredients, product) = lines[0].split_once_checked("=>").unwrap();
+        assert_eq!((ingredients.text, product.trim().column), ("10 ORE ", 11));
+        assert_eq!(lines[1].split_once_checked(",").unwrap_err(), AoCError::parse_error(2, 1, "expected ','"));
+    }
+
+    #[test]
+    fn keep_trailing_newline() {
+        let input = PuzzleInput::with_trailing_newline("1\r\n2\r\n");
+        assert_eq!(input.as_str(), "1\n2\n");
+    }
+}
@@ -0,0 +1,45 @@
+use crate::aoc_day::AoCDay;
+use crate::aoc_year::{AoCYear, DayInfo};
+
+// Entry for a single day, submitted by `make_day!`
+pub struct DayRegistration {
+    pub info: DayInfo,
+    pub solver: &'static dyn AoCDay,
+}
+
+// Entry for a year crate, submitted by `make_year!`
+pub struct YearRegistration {
+    pub year: &'static dyn AoCYear,
+}
+
+inventory::collect!(DayRegistration);
+inventory::collect!(YearRegistration);
+
+// All registered years in ascending order
+pub fn years() -> Vec<&'static dyn AoCYear> {
+    let mut years: Vec<_> = inventory::iter::<YearRegistration>
+        .into_iter()
+        .map(|r| r.year)
+        .collect();
+
+    years.sort_unstable_by_key(|y| y.year());
+    years
+}
+
+pub fn get_year(year: u16) -> Option<&'static dyn AoCYear> {
+    inventory::iter::<YearRegistration>
+        .into_iter()
+        .map(|r| r.year)
+        .find(|y| y.year() == year)
+}
+
+// Registered days of a year in ascending order
+pub fn day_registrations(year: u16) -> impl Iterator<Item = &'static DayRegistration> {
+    let mut days: Vec<_> = inventory::iter::<DayRegistration>
+        .into_iter()
+        .filter(|r| r.info.year == year)
+        .collect();
+
+    days.sort_unstable_by_key(|r| r.info.day);
+    days.into_iter()
+}
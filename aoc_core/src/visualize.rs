@@ -0,0 +1,161 @@
+use crate::io_context::{Frame, FrameSink};
+use std::fmt::Write as _;
+use std::io::{self, Write};
+use std::thread;
+use std::time::Duration;
+
+// Moves the cursor to the top left corner and clears the screen
+const CLEAR_SCREEN: &str = "\x1b[H\x1b[2J";
+
+// Plays the frames in a terminal, waiting `delay` after each one
+pub struct TerminalPlayer<W: Write> {
+    out: W,
+    delay: Duration,
+    error: Option<io::Error>,
+}
+
+impl<W: Write> TerminalPlayer<W> {
+    pub fn new(out: W, delay: Duration) -> Self {
+        TerminalPlayer { out, delay, error: None }
+    }
+}
+
+impl<W: Write> FrameSink for TerminalPlayer<W> {
+    fn show(&mut self, frame: &Frame) {
+        let screen = format!("{}{}", CLEAR_SCREEN, render(frame, "\n"));
+        if let Err(err) = self.out.write_all(screen.as_bytes()).and_then(|_| self.out.flush()) {
+            self.error.get_or_insert(err);
+        }
+        thread::sleep(self.delay);
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        self.error.take().map_or(Ok(()), Err)
+    }
+}
+
+// Writes all frames one after another as plain text, each with a numbered header line
+pub struct TextDump<W: Write> {
+    out: W,
+    count: usize,
+    error: Option<io::Error>,
+}
+
+impl<W: Write> TextDump<W> {
+    pub fn new(out: W) -> Self {
+        TextDump { out, count: 0, error: None }
+    }
+}
+
+impl<W: Write> FrameSink for TextDump<W> {
+    fn show(&mut self, frame: &Frame) {
+        self.count += 1;
+        let text = format!("--- Frame {} ---\n{}\n", self.count, render(frame, "\n"));
+        if let Err(err) = self.out.write_all(text.as_bytes()) {
+            self.error.get_or_insert(err);
+        }
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        self.error.take().map_or(Ok(()), Err).and_then(|_| self.out.flush())
+    }
+}
+
+// Records the frames as an asciinema (v2) recording, played back with `delay` between frames.
+// The size of the terminal has to be written first, so the frames are kept until `finish`.
+pub struct CastWriter<W: Write> {
+    out: W,
+    delay: Duration,
+    frames: Vec<String>,
+    width: usize,
+    height: usize,
+}
+
+impl<W: Write> CastWriter<W> {
+    pub fn new(out: W, delay: Duration) -> Self {
+        CastWriter { out, delay, frames: vec![], width: 1, height: 1 }
+    }
+}
+
+impl<W: Write> FrameSink for CastWriter<W> {
+    fn show(&mut self, frame: &Frame) {
+        let rendered = render(frame, "\r\n");
+        self.width = self.width.max(rendered.lines().map(|l| l.chars().count()).max().unwrap_or(0));
+        self.height = self.height.max(rendered.lines().count());
+        self.frames.push(rendered);
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        writeln!(self.out, "{{\"version\": 2, \"width\": {}, \"height\": {}}}", self.width, self.height)?;
+        for (i, frame) in self.frames.drain(..).enumerate() {
+            let time = self.delay.as_secs_f64() * i as f64;
+            writeln!(self.out, "[{:.3}, \"o\", {}]", time, json_string(&format!("{}{}", CLEAR_SCREEN, frame)))?;
+        }
+        self.out.flush()
+    }
+}
+
+// Lines of the frame followed by the caption (if there is one)
+fn render(frame: &Frame, line_break: &str) -> String {
+    let mut lines = frame.lines.clone();
+    if !frame.caption.is_empty() {
+        lines.push(frame.caption.clone());
+    }
+    lines.iter().map(|line| format!("{}{}", line, line_break)).collect()
+}
+
+fn json_string(text: &str) -> String {
+    let mut json = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            c if c.is_control() => {
+                let _ = write!(json, "\\u{:04x}", c as u32);
+            }
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frames() -> [Frame; 2] {
+        [
+            Frame::new(vec!["#.".to_string(), ".#".to_string()], "Score: 1"),
+            Frame::new(vec!["\"o\"".to_string()], ""),
+        ]
+    }
+
+    #[test]
+    fn text_dump() {
+        let mut out = vec![];
+        let mut dump = TextDump::new(&mut out);
+        frames().iter().for_each(|f| dump.show(f));
+        dump.finish().unwrap();
+        drop(dump);
+        assert_eq!(String::from_utf8(out).unwrap(), "--- Frame 1 ---\n#.\n.#\nScore: 1\n\n--- Frame 2 ---\n\"o\"\n\n");
+    }
+
+    #[test]
+    fn cast_file() {
+        let mut out = vec![];
+        let mut cast = CastWriter::new(&mut out, Duration::from_millis(250));
+        frames().iter().for_each(|f| cast.show(f));
+        cast.finish().unwrap();
+        drop(cast);
+
+        let text = String::from_utf8(out).unwrap();
+        let lines: Vec<_> = text.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], "{\"version\": 2, \"width\": 8, \"height\": 3}");
+        assert_eq!(lines[1], "[0.000, \"o\", \"\\u001b[H\\u001b[2J#.\\r\\n.#\\r\\nScore: 1\\r\\n\"]");
+        assert_eq!(lines[2], "[0.250, \"o\", \"\\u001b[H\\u001b[2J\\\"o\\\"\\r\\n\"]");
+    }
+}
@@ -0,0 +1,24 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+
+// Year crates register their days with `aoc_core::registry` on their own, but they are only
+// linked into the binary if referenced. Generate a reference for every `aoc_<year>` dependency,
+// so adding a new year only requires adding it to Cargo.toml.
+fn main() {
+    println!("cargo:rerun-if-changed=Cargo.toml");
+
+    let manifest = fs::read_to_string("Cargo.toml").expect("Cargo.toml not found");
+    let year_crates: String = manifest
+        .lines()
+        .filter_map(|l| l.split_once('=').map(|(name, _)| name.trim()))
+        .filter(|name| {
+            name.strip_prefix("aoc_")
+                .is_some_and(|year| year.len() == 4 && year.chars().all(|c| c.is_ascii_digit()))
+        })
+        .map(|name| format!("use {} as _;\n", name))
+        .collect();
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("year_crates.rs"), year_crates).unwrap();
+}
@@ -0,0 +1,135 @@
+use serde::Serialize;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+// Counters of the global allocator, they are only updated with the "alloc-stats" feature
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED_BYTES: AtomicU64 = AtomicU64::new(0);
+static LIVE_BYTES: AtomicU64 = AtomicU64::new(0);
+static PEAK_LIVE_BYTES: AtomicU64 = AtomicU64::new(0);
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+// System allocator that keeps track of all allocations of the process
+#[cfg(feature = "alloc-stats")]
+struct CountingAllocator;
+
+#[cfg(feature = "alloc-stats")]
+unsafe impl std::alloc::GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: std::alloc::Layout) -> *mut u8 {
+        let ptr = unsafe { std::alloc::System.alloc(layout) };
+        if !ptr.is_null() {
+            record_allocation(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: std::alloc::Layout) -> *mut u8 {
+        let ptr = unsafe { std::alloc::System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            record_allocation(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: std::alloc::Layout) {
+        unsafe { std::alloc::System.dealloc(ptr, layout) };
+        LIVE_BYTES.fetch_sub(layout.size() as u64, Ordering::Relaxed);
+    }
+
+    // Counts as a new allocation of the full new size
+    unsafe fn realloc(&self, ptr: *mut u8, layout: std::alloc::Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { std::alloc::System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            record_allocation(new_size, layout.size());
+        }
+        new_ptr
+    }
+}
+
+#[cfg(feature = "alloc-stats")]
+fn record_allocation(size: usize, freed: usize) {
+    let (size, freed) = (size as u64, freed as u64);
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    ALLOCATED_BYTES.fetch_add(size, Ordering::Relaxed);
+    let live = LIVE_BYTES.fetch_add(size, Ordering::Relaxed) + size;
+    LIVE_BYTES.fetch_sub(freed, Ordering::Relaxed);
+    PEAK_LIVE_BYTES.fetch_max(live - freed, Ordering::Relaxed);
+}
+
+// Allocations made while running a function
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct AllocStats {
+    pub allocations: u64,
+    pub bytes: u64,
+    // Highest amount of memory held at once, on top of what was allocated before
+    pub peak_bytes: u64,
+}
+
+pub fn enabled() -> bool {
+    cfg!(feature = "alloc-stats")
+}
+
+// Counts the allocations of the function (None without the "alloc-stats" feature). The counters
+// are global, so allocations of other threads running at the same time are included.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    if !enabled() {
+        return (f(), None);
+    }
+
+    let live = LIVE_BYTES.load(Ordering::Relaxed);
+    PEAK_LIVE_BYTES.store(live, Ordering::Relaxed);
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = ALLOCATED_BYTES.load(Ordering::Relaxed);
+
+    let value = f();
+
+    let stats = AllocStats {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        bytes: ALLOCATED_BYTES.load(Ordering::Relaxed) - bytes,
+        peak_bytes: PEAK_LIVE_BYTES.load(Ordering::Relaxed).saturating_sub(live),
+    };
+    (value, Some(stats))
+}
+
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 { format!("{} B", bytes) } else { format!("{:.1} {}", value, UNITS[unit]) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc-stats")]
+    fn count_allocations() {
+        let (_, stats) = measure(|| {
+            let mut v: Vec<u8> = Vec::with_capacity(4096);
+            v.extend(std::iter::repeat_n(1, 8192));
+            drop(v);
+            Box::new([0u8; 100])
+        });
+
+        // Other tests run at the same time, so only lower bounds are known
+        let stats = stats.unwrap();
+        assert!(stats.allocations >= 3);
+        assert!(stats.bytes >= 4096 + 8192 + 100);
+        assert!(stats.peak_bytes >= 8192);
+    }
+}
@@ -0,0 +1,94 @@
+use aoc_core::AoCResult;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+// Known answers of a year, stored in answers/<year>.toml:
+//
+// [day01]
+// part1 = 3363033
+// part2 = "some text"
+pub struct AnswerStore {
+    year: u16,
+    answers: BTreeMap<String, BTreeMap<String, toml::Value>>,
+}
+
+impl AnswerStore {
+    pub fn path(year: u16) -> PathBuf {
+        PathBuf::from("answers").join(format!("{}.toml", year))
+    }
+
+    // Loads the answers of a year (a missing file is treated as an empty store)
+    pub fn load(year: u16) -> Result<Self, String> {
+        let path = Self::path(year);
+        let answers = match fs::read_to_string(&path) {
+            Ok(content) => toml::from_str(&content).map_err(|err| format!("{}: {}", path.display(), err))?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(err) => return Err(format!("{}: {}", path.display(), err)),
+        };
+
+        Ok(AnswerStore { year, answers })
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = Self::path(self.year);
+        let content = toml::to_string(&self.answers).map_err(|err| err.to_string())?;
+        fs::create_dir_all(path.parent().unwrap())
+            .and_then(|_| fs::write(&path, content))
+            .map_err(|err| format!("{}: {}", path.display(), err))
+    }
+
+    // Expected answer in its displayed form
+    pub fn get(&self, day: u8, part: u8) -> Option<String> {
+        match self.answers.get(&day_key(day))?.get(&part_key(part))? {
+            toml::Value::String(s) => Some(s.clone()),
+            other => Some(other.to_string()),
+        }
+    }
+
+    // Stores an answer, returns false for results that cannot be stored
+    pub fn set(&mut self, day: u8, part: u8, result: &AoCResult) -> bool {
+        let value = match result {
+            AoCResult::Num(_) | AoCResult::Int(_) | AoCResult::Wide(_) => match i64::try_from(result.clone()) {
+                Ok(n) => toml::Value::Integer(n),
+                // TOML integers are limited to 64 bits (signed)
+                Err(_) => toml::Value::String(result.to_string()),
+            },
+            AoCResult::Str(_) | AoCResult::Grid(_) => toml::Value::String(result.to_string()),
+            AoCResult::PrintedToConsole | AoCResult::NotImplemented => return false,
+        };
+
+        self.answers.entry(day_key(day)).or_default().insert(part_key(part), value);
+        true
+    }
+}
+
+pub fn day_key(day: u8) -> String {
+    format!("day{:02}", day)
+}
+
+pub fn part_key(part: u8) -> String {
+    format!("part{}", part)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let mut store = AnswerStore { year: 2019, answers: BTreeMap::new() };
+        store.set(1, 1, &AoCResult::Num(3363033));
+        store.set(8, 2, &AoCResult::Str("ABCDE".to_string()));
+        store.set(25, 2, &AoCResult::NotImplemented);
+        store.set(22, 2, &AoCResult::Wide(-(i64::MAX as i128) * 4));
+
+        let content = toml::to_string(&store.answers).unwrap();
+        let store = AnswerStore { year: 2019, answers: toml::from_str(&content).unwrap() };
+        assert_eq!(store.get(1, 1), Some("3363033".to_string()));
+        assert_eq!(store.get(8, 2), Some("ABCDE".to_string()));
+        assert_eq!(store.get(25, 2), None);
+        assert_eq!(store.get(22, 2), Some("-36893488147419103228".to_string()));
+    }
+}
@@ -0,0 +1,157 @@
+use crate::alloc_stats::{self, AllocStats};
+use crate::inputs::InputSource;
+use aoc_core::{AoCError, AoCYear, IoContext, Params};
+use serde::Serialize;
+use std::fs;
+use std::time::{Duration, Instant};
+
+// Timing statistics over all runs (in milliseconds)
+#[derive(Serialize)]
+pub struct Stats {
+    pub min_ms: f64,
+    pub median_ms: f64,
+    pub mean_ms: f64,
+    pub stddev_ms: f64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut ms: Vec<f64> = samples.iter().map(|d| d.as_secs_f64() * 1000.0).collect();
+        ms.sort_by(|a, b| a.total_cmp(b));
+
+        let n = ms.len() as f64;
+        let mean = ms.iter().sum::<f64>() / n;
+        let variance = ms.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+        let median = if ms.len().is_multiple_of(2) {
+            (ms[ms.len() / 2 - 1] + ms[ms.len() / 2]) / 2.0
+        } else {
+            ms[ms.len() / 2]
+        };
+
+        Stats {
+            min_ms: ms[0],
+            median_ms: median,
+            mean_ms: mean,
+            stddev_ms: variance.sqrt(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct BenchResult {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub runs: u32,
+    pub answer: String,
+    pub answer_type: &'static str,
+    // Parsing the input, it is read only once before the first run
+    pub parse: Stats,
+    // Solving the part from the parsed input
+    pub solve: Stats,
+    // Allocations of the last run (without reading the input), only with the "alloc-stats" feature
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_alloc: Option<AllocStats>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub solve_alloc: Option<AllocStats>,
+}
+
+pub fn bench(
+    year: &dyn AoCYear,
+    inputs: &InputSource,
+    day: u8,
+    part: u8,
+    runs: u32,
+    params: &Params,
+) -> Result<BenchResult, AoCError> {
+    let solver = year.get_day(day).ok_or(AoCError::InvalidDay(day))?;
+    // Read only once, as stdin cannot be read again and reading the file is not part of the solver
+    let input = inputs.read(year.year(), day)?;
+    let mut parse_times = Vec::with_capacity(runs as usize);
+    let mut solve_times = Vec::with_capacity(runs as usize);
+    let mut answer = String::new();
+    let mut answer_type = "";
+    let (mut parse_alloc, mut solve_alloc) = (None, None);
+
+    for _ in 0..runs {
+        let start = Instant::now();
+        let (parsed, alloc) = alloc_stats::measure(|| solver.parse(&input));
+        let parsed = parsed?;
+        parse_times.push(start.elapsed());
+        parse_alloc = alloc;
+
+        let start = Instant::now();
+        let (res, alloc) = alloc_stats::measure(|| solver.solve_parsed(part, &parsed, params, &mut IoContext::silent()));
+        let res = res?;
+        solve_times.push(start.elapsed());
+        solve_alloc = alloc;
+
+        answer = res.to_string();
+        answer_type = res.kind();
+    }
+
+    Ok(BenchResult {
+        year: year.year(),
+        day,
+        part,
+        runs,
+        answer,
+        answer_type,
+        parse: Stats::from_samples(&parse_times),
+        solve: Stats::from_samples(&solve_times),
+        parse_alloc,
+        solve_alloc,
+    })
+}
+
+pub fn print_result(result: &BenchResult) {
+    println!("Day {:02} (part {}): {} ({} runs)", result.day, result.part, result.answer, result.runs);
+    println!("{:<6}  {:>12}  {:>12}  {:>12}  {:>12}", "", "min", "median", "mean", "stddev");
+    for (name, stats) in [("parse", &result.parse), ("solve", &result.solve)] {
+        println!(
+            "{:<6}  {:>9.3} ms  {:>9.3} ms  {:>9.3} ms  {:>9.3} ms",
+            name, stats.min_ms, stats.median_ms, stats.mean_ms, stats.stddev_ms
+        );
+    }
+
+    if let (Some(parse), Some(solve)) = (&result.parse_alloc, &result.solve_alloc) {
+        println!();
+        println!("{:<6}  {:>12}  {:>12}  {:>12}", "", "allocations", "allocated", "peak");
+        for (name, alloc) in [("parse", parse), ("solve", solve)] {
+            println!(
+                "{:<6}  {:>12}  {:>12}  {:>12}",
+                name,
+                alloc.allocations,
+                alloc_stats::format_bytes(alloc.bytes),
+                alloc_stats::format_bytes(alloc.peak_bytes)
+            );
+        }
+    }
+}
+
+pub fn write_json(result: &BenchResult, path: &str) -> std::io::Result<()> {
+    fs::write(path, serde_json::to_string_pretty(result)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_odd_samples() {
+        let samples = [3, 1, 2].map(Duration::from_millis);
+        let stats = Stats::from_samples(&samples);
+        assert_eq!(stats.min_ms, 1.0);
+        assert_eq!(stats.median_ms, 2.0);
+        assert_eq!(stats.mean_ms, 2.0);
+        assert!((stats.stddev_ms - (2.0f64 / 3.0).sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn stats_even_samples() {
+        let samples = [4, 1, 3, 2].map(Duration::from_millis);
+        let stats = Stats::from_samples(&samples);
+        assert_eq!(stats.median_ms, 2.5);
+        assert_eq!(stats.mean_ms, 2.5);
+    }
+}
@@ -0,0 +1,215 @@
+use crate::config::Config;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::time::Duration;
+
+// Identifies the tool to the site operators, as asked for by adventofcode.com
+const USER_AGENT: &str = concat!("github.com/devanescent/aoc_rust/", env!("CARGO_PKG_VERSION"));
+
+// Response of the puzzle site to a submitted answer
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    // Wrong, without a hint
+    Wrong,
+    // Answer was submitted too recently (remaining seconds), it was not checked
+    Wait(u64),
+    AlreadySolved,
+    // Message that could not be recognized
+    Unknown(String),
+}
+
+impl Outcome {
+    // Reads the message of the <article> element of the response page
+    pub fn parse(html: &str) -> Outcome {
+        let message = html
+            .split_once("<article>")
+            .and_then(|(_, rest)| rest.split_once("</article>"))
+            .map_or(html, |(article, _)| article);
+        let text = strip_tags(message);
+
+        if text.contains("That's the right answer") {
+            Outcome::Correct
+        } else if text.contains("your answer is too high") {
+            Outcome::TooHigh
+        } else if text.contains("your answer is too low") {
+            Outcome::TooLow
+        } else if text.contains("That's not the right answer") {
+            Outcome::Wrong
+        } else if text.contains("You gave an answer too recently") {
+            Outcome::Wait(wait_seconds(&text))
+        } else if text.contains("You don't seem to be solving the right level") {
+            Outcome::AlreadySolved
+        } else {
+            Outcome::Unknown(text)
+        }
+    }
+
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong)
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "too high"),
+            Outcome::TooLow => write!(f, "too low"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::Wait(seconds) => write!(f, "wait {} seconds", seconds),
+            Outcome::AlreadySolved => write!(f, "already solved"),
+            Outcome::Unknown(text) => write!(f, "unknown response: {}", text),
+        }
+    }
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+// "You have 1m 25s left to wait." (0 if the time is not given)
+fn wait_seconds(text: &str) -> u64 {
+    let Some((_, rest)) = text.split_once("You have ") else {
+        return 0;
+    };
+    let Some((time, _)) = rest.split_once(" left to wait") else {
+        return 0;
+    };
+
+    time.split_whitespace()
+        .filter_map(|t| match t.split_at(t.len().saturating_sub(1)) {
+            (n, "m") => n.parse::<u64>().ok().map(|m| m * 60),
+            (n, "s") => n.parse::<u64>().ok(),
+            _ => None,
+        })
+        .sum()
+}
+
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new(config: &Config) -> Result<Self, String> {
+        let agent = ureq::Agent::config_builder()
+            .user_agent(USER_AGENT)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .new_agent();
+
+        Ok(Client { agent, base_url: config.base_url().to_string(), session: config.session()?.to_string() })
+    }
+
+    pub fn submit(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<Outcome, String> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let html = self
+            .agent
+            .post(&url)
+            .header("Cookie", format!("session={}", self.session))
+            .send_form([("level", part.to_string()), ("answer", answer.to_string())])
+            .and_then(|mut response| response.body_mut().read_to_string())
+            .map_err(|err| format!("{}: {}", url, err))?;
+
+        Ok(Outcome::parse(&html))
+    }
+
+    pub fn fetch_input(&self, year: u16, day: u8) -> Result<String, String> {
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        self.agent
+            .get(&url)
+            .header("Cookie", format!("session={}", self.session))
+            .call()
+            .and_then(|mut response| response.body_mut().read_to_string())
+            .map_err(|err| format!("{}: {}", url, err))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    #[test]
+    fn parse_responses() {
+        let page = |message: &str| format!("<html><main>\n<article><p>{}</p></article>\n</main></html>", message);
+        assert_eq!(
+            Outcome::parse(&page("That's the right answer!  You are <em>one gold star</em> closer.")),
+            Outcome::Correct
+        );
+        assert_eq!(
+            Outcome::parse(&page("That's not the right answer; your answer is too high.  Please wait one minute.")),
+            Outcome::TooHigh
+        );
+        assert_eq!(
+            Outcome::parse(&page("That's not the right answer.  If you're stuck, make sure you're using the full input")),
+            Outcome::Wrong
+        );
+        assert_eq!(
+            Outcome::parse(&page("You gave an answer too recently.  You have 1m 25s left to wait.")),
+            Outcome::Wait(85)
+        );
+        assert_eq!(
+            Outcome::parse(&page("You don't seem to be solving the right level.  Did you already complete it?")),
+            Outcome::AlreadySolved
+        );
+    }
+
+    #[test]
+    fn submit_to_mock_server() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut head = vec![];
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim().is_empty() {
+                    break;
+                }
+                head.push(line.trim().to_string());
+            }
+
+            let length: usize = head
+                .iter()
+                .find_map(|h| h.to_lowercase().strip_prefix("content-length:").map(|l| l.trim().parse().unwrap()))
+                .unwrap();
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).unwrap();
+
+            let html = "<article><p>That's the right answer!</p></article>";
+            write!(reader.get_mut(), "HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n{}", html.len(), html).unwrap();
+            (head, String::from_utf8(body).unwrap())
+        });
+
+        let config = Config { session: Some("abc".to_string()), base_url: Some(base_url) };
+        let outcome = Client::new(&config).unwrap().submit(2019, 1, 2, "42").unwrap();
+        assert_eq!(outcome, Outcome::Correct);
+
+        let (head, body) = server.join().unwrap();
+        assert_eq!(head[0], "POST /2019/day/1/answer HTTP/1.1");
+        assert!(head.iter().any(|h| h.eq_ignore_ascii_case("cookie: session=abc")));
+        assert!(head.iter().any(|h| h.to_lowercase().starts_with("user-agent: github.com/devanescent/aoc_rust")));
+        assert_eq!(body, "level=2&answer=42");
+    }
+}
@@ -0,0 +1,69 @@
+use serde::Deserialize;
+use std::env;
+use std::fs;
+use std::path::Path;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+// Settings for talking to the puzzle site, read from aoc.toml in the working directory:
+//
+// session = "53616c7465645f5f..."
+// base_url = "http://localhost:8080"
+//
+// The environment variables AOC_SESSION and AOC_BASE_URL take precedence.
+#[derive(Debug, Default, PartialEq, Eq, Deserialize)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: Option<String>,
+}
+
+impl Config {
+    pub fn load() -> Result<Self, String> {
+        let path = Path::new("aoc.toml");
+        let content = fs::read_to_string(path).ok();
+        Self::parse(content.as_deref(), |name| env::var(name).ok()).map_err(|err| format!("{}: {}", path.display(), err))
+    }
+
+    fn parse(content: Option<&str>, env: impl Fn(&str) -> Option<String>) -> Result<Self, String> {
+        let mut config: Config = match content {
+            Some(content) => toml::from_str(content).map_err(|err| err.to_string())?,
+            None => Config::default(),
+        };
+
+        if let Some(session) = env("AOC_SESSION") {
+            config.session = Some(session);
+        }
+        if let Some(base_url) = env("AOC_BASE_URL") {
+            config.base_url = Some(base_url);
+        }
+        Ok(config)
+    }
+
+    pub fn base_url(&self) -> &str {
+        self.base_url.as_deref().unwrap_or(DEFAULT_BASE_URL).trim_end_matches('/')
+    }
+
+    pub fn session(&self) -> Result<&str, String> {
+        self.session
+            .as_deref()
+            .filter(|s| !s.is_empty())
+            .ok_or_else(|| "no session token, set AOC_SESSION or 'session' in aoc.toml".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn environment_overrides_file() {
+        let content = "session = \"abc\"\nbase_url = \"http://localhost:8080/\"";
+        let config = Config::parse(Some(content), |name| (name == "AOC_SESSION").then(|| "xyz".to_string())).unwrap();
+        assert_eq!(config.session(), Ok("xyz"));
+        assert_eq!(config.base_url(), "http://localhost:8080");
+
+        let config = Config::parse(None, |_| None).unwrap();
+        assert!(config.session().is_err());
+        assert_eq!(config.base_url(), DEFAULT_BASE_URL);
+    }
+}
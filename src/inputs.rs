@@ -0,0 +1,131 @@
+use crate::client::Client;
+use aoc_core::{AoCError, PuzzleInput};
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+// Where puzzle inputs are read from
+pub enum InputSource {
+    // Directory with one file per day: <dir>/<year>/day<NN>.txt
+    Layout(PathBuf),
+    // Explicit file, "-" reads from stdin
+    File(String),
+    // Like `Layout`, but missing inputs are downloaded first
+    Fetch(PathBuf, Client),
+}
+
+impl InputSource {
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        match self {
+            InputSource::Layout(dir) | InputSource::Fetch(dir, _) => {
+                dir.join(year.to_string()).join(format!("day{:02}.txt", day))
+            }
+            InputSource::File(path) => PathBuf::from(path),
+        }
+    }
+
+    pub fn read(&self, year: u16, day: u8) -> Result<PuzzleInput, AoCError> {
+        if let InputSource::File(path) = self
+            && path == "-"
+        {
+            return read_stdin().map(PuzzleInput::from);
+        }
+
+        let path = self.path(year, day);
+        if let InputSource::Fetch(_, client) = self {
+            fetch(client, &path, year, day)
+                .map_err(|err| AoCError::MissingInput(format!("{} (download failed: {})", path.display(), err)))?;
+        }
+
+        fs::read_to_string(&path).map(PuzzleInput::from).map_err(|err| match err.kind() {
+            io::ErrorKind::NotFound => AoCError::MissingInput(path.display().to_string()),
+            _ => AoCError::UnreadableInput(format!("{}: {}", path.display(), err)),
+        })
+    }
+}
+
+// Downloads the input to the given path unless it is already there, returns whether it was downloaded.
+// Empty files (e.g. the placeholders created by `new`) do not count as inputs.
+pub fn fetch(client: &Client, path: &Path, year: u16, day: u8) -> Result<bool, String> {
+    if fs::metadata(path).is_ok_and(|m| m.len() > 0) {
+        return Ok(false);
+    }
+
+    let input = client.fetch_input(year, day)?;
+    fs::create_dir_all(path.parent().unwrap())
+        .and_then(|_| fs::write(path, input))
+        .map_err(|err| format!("{}: {}", path.display(), err))?;
+    Ok(true)
+}
+
+// Stdin can only be consumed once, so keep its content for repeated reads
+fn read_stdin() -> Result<String, AoCError> {
+    static STDIN: OnceLock<Result<String, String>> = OnceLock::new();
+
+    STDIN
+        .get_or_init(|| {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input).map(|_| input).map_err(|err| err.to_string())
+        })
+        .clone()
+        .map_err(|err| AoCError::UnreadableInput(format!("<stdin>: {}", err)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::{env, thread};
+
+    #[test]
+    fn layout_path() {
+        let source = InputSource::Layout(PathBuf::from("inputs"));
+        assert_eq!(source.path(2019, 5), PathBuf::from("inputs/2019/day05.txt"));
+    }
+
+    #[test]
+    fn missing_input_names_path() {
+        let source = InputSource::Layout(PathBuf::from("no_such_dir"));
+        let err = source.read(2025, 12).unwrap_err();
+        assert_eq!(err, AoCError::MissingInput(source.path(2025, 12).display().to_string()));
+    }
+
+    #[test]
+    fn unreadable_input_is_not_missing() {
+        // A directory exists, but cannot be read as a file
+        let dir = env::temp_dir();
+        let source = InputSource::File(dir.display().to_string());
+        let err = source.read(2025, 12).unwrap_err();
+        assert!(matches!(err, AoCError::UnreadableInput(msg) if msg.starts_with(&dir.display().to_string())));
+    }
+
+    #[test]
+    fn fetch_downloads_only_once() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        // Answers a single request, so a second download would fail
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            reader.read_line(&mut request).unwrap();
+            while reader.read_line(&mut String::new()).unwrap() > 2 {}
+            write!(reader.get_mut(), "HTTP/1.1 200 OK\r\nContent-Length: 4\r\n\r\n1\n2\n").unwrap();
+            request
+        });
+
+        let dir = env::temp_dir().join(format!("aoc_rust_fetch_{}", std::process::id()));
+        let config = Config { session: Some("abc".to_string()), base_url: Some(base_url) };
+        let source = InputSource::Fetch(dir.clone(), Client::new(&config).unwrap());
+
+        assert_eq!(source.read(2019, 1), Ok(PuzzleInput::new("1\n2")));
+        assert_eq!(server.join().unwrap().trim(), "GET /2019/day/1/input HTTP/1.1");
+        assert_eq!(source.read(2019, 1), Ok(PuzzleInput::new("1\n2")));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,34 +1,248 @@
-use aoc_core::AoCResult;
-use clap::Parser;
-use std::fs;
+mod alloc_stats;
+mod answers;
+mod bench;
+mod client;
+mod config;
+mod inputs;
+mod output;
+mod report;
+mod runner;
+mod scaffold;
+mod status;
+mod submissions;
+
+use answers::AnswerStore;
+use client::Outcome;
+use aoc_core::io_context::FrameSink;
+use aoc_core::visualize::{CastWriter, TerminalPlayer, TextDump};
+use aoc_core::{registry, AoCResult, AoCYear, IoContext, Params};
+use clap::{Args, Parser, Subcommand};
+use runner::PartRun;
+use submissions::SubmissionLog;
+use inputs::InputSource;
+use output::{BenchRecord, Format, Record};
+use std::fmt::Display;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Cursor, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::thread;
+use std::time::Duration;
+
+// Links all year crates, see build.rs
+include!(concat!(env!("OUT_DIR"), "/year_crates.rs"));
 
 #[derive(Parser)]
-struct Args {
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    run: Option<RunArgs>,
+
+    #[command(flatten)]
+    input: InputArgs,
+
+    /// Output format of the results
+    #[arg(long, global = true, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// Print debug output of the solvers to stderr (-vv for more details)
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a single part repeatedly and report timing statistics
+    Bench(BenchArgs),
+    /// Create the module of a new day (and its year crate if needed)
+    New(NewArgs),
+    /// Show which days are implemented and which answers are verified
+    Status(StatusArgs),
+    /// Solve a part and submit the answer to the puzzle site
+    Submit(SubmitArgs),
+    /// Download the input of a day into the input directory
+    Fetch(FetchArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
     #[arg(value_parser = validate_year)]
     year: u16,
 
+    /// Day number or "all" to run every day of the year
     #[arg(value_parser = validate_day)]
+    day: DaySelection,
+
+    #[arg(default_value = "1", value_parser = validate_part)]
+    part: u8,
+
+    /// Compare the answers against answers/<year>.toml
+    #[arg(long, conflicts_with = "record")]
+    check: bool,
+
+    /// Store the answers in answers/<year>.toml
+    #[arg(long)]
+    record: bool,
+
+    /// Override a puzzle parameter of the day, e.g. --param steps=10
+    #[arg(long = "param", value_name = "KEY=VALUE", value_parser = parse_param)]
+    params: Vec<(String, String)>,
+
+    /// Answer prompts of interactive days with the lines of this file instead of the terminal
+    #[arg(long)]
+    script: Option<PathBuf>,
+
+    /// Number of days run in parallel with "all" (default: number of CPUs, 1 runs them one after another)
+    #[arg(short, long, value_parser = clap::value_parser!(u16).range(1..))]
+    jobs: Option<u16>,
+
+    /// Time limit for parsing and for each part, in seconds (0 for no limit, interactive days answered at the terminal have none)
+    #[arg(long, value_name = "SECONDS", default_value_t = 60)]
+    timeout: u64,
+
+    /// Show the animation of the day in the terminal, or write its frames to FILE (.cast for an asciinema recording, plain text otherwise)
+    #[arg(long, value_name = "FILE", num_args = 0..=1, require_equals = true, default_missing_value = "-")]
+    visualize: Option<PathBuf>,
+
+    /// Delay between the frames of --visualize, in milliseconds
+    #[arg(long, value_name = "MS", default_value_t = 50)]
+    frame_delay: u64,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    #[arg(value_parser = validate_year)]
+    year: u16,
+
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
     day: u8,
 
     #[arg(default_value = "1", value_parser = validate_part)]
     part: u8,
+
+    /// Number of runs
+    #[arg(short = 'n', long, default_value = "10", value_parser = clap::value_parser!(u32).range(1..))]
+    runs: u32,
+
+    /// Write the results to this file as JSON
+    #[arg(long)]
+    json: Option<String>,
+
+    /// Override a puzzle parameter of the day, e.g. --param steps=10
+    #[arg(long = "param", value_name = "KEY=VALUE", value_parser = parse_param)]
+    params: Vec<(String, String)>,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    #[arg(value_parser = clap::value_parser!(u16).range(2015..))]
+    year: u16,
+
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+}
+
+#[derive(Args)]
+struct StatusArgs {
+    /// Only show this year
+    #[arg(value_parser = validate_year)]
+    year: Option<u16>,
+
+    /// Print the calendars as markdown tables
+    #[arg(long)]
+    markdown: bool,
+
+    /// Time limit for parsing and for each part, in seconds (0 for no limit)
+    #[arg(long, value_name = "SECONDS", default_value_t = 60)]
+    timeout: u64,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    #[arg(value_parser = validate_year)]
+    year: u16,
+
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    #[arg(value_parser = validate_part)]
+    part: u8,
+
+    /// Submit this answer instead of solving the part
+    #[arg(long)]
+    answer: Option<String>,
+
+    /// Time limit for parsing and for solving the part, in seconds (0 for no limit, interactive days have none)
+    #[arg(long, value_name = "SECONDS", default_value_t = 60)]
+    timeout: u64,
+}
+
+#[derive(Args)]
+struct FetchArgs {
+    #[arg(value_parser = clap::value_parser!(u16).range(2015..))]
+    year: u16,
+
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+}
+
+#[derive(Args)]
+struct InputArgs {
+    /// Read the input from this file instead ("-" for stdin)
+    #[arg(long, global = true)]
+    input: Option<String>,
+
+    /// Directory containing the inputs as <year>/day<NN>.txt
+    #[arg(long, global = true, default_value = "inputs")]
+    input_dir: PathBuf,
+
+    /// Download missing inputs from the puzzle site (see aoc.toml)
+    #[arg(long, global = true, conflicts_with = "input")]
+    fetch: bool,
+}
+
+impl InputArgs {
+    fn source(&self) -> InputSource {
+        match &self.input {
+            Some(path) => InputSource::File(path.clone()),
+            None if self.fetch => InputSource::Fetch(self.input_dir.clone(), client()),
+            None => InputSource::Layout(self.input_dir.clone()),
+        }
+    }
 }
 
 fn validate_year(input: &str) -> Result<u16, String> {
-    let err_msg = "Valid years: 2019, 2015".to_string();
+    let err_msg = format!(
+        "Valid years: {}",
+        registry::years().iter().map(|y| y.year().to_string()).collect::<Vec<_>>().join(", ")
+    );
     let year: u16 = input.parse().map_err(|_| &err_msg)?;
-    if year == 2019 || year == 2025 {
-         Ok(year)
+    if registry::get_year(year).is_some() {
+        Ok(year)
     } else {
         Err(err_msg)
     }
 }
 
-fn validate_day(input: &str) -> Result<u8, String> {
-    let err_msg = "Day must be between 01 and 25".to_string();
+#[derive(Clone, Copy)]
+enum DaySelection {
+    Single(u8),
+    All,
+}
+
+fn validate_day(input: &str) -> Result<DaySelection, String> {
+    if input == "all" {
+        return Ok(DaySelection::All);
+    }
+
+    let err_msg = "Day must be between 01 and 25 or 'all'".to_string();
     let day: u8 = input.parse().map_err(|_| &err_msg)?;
-    if day >= 1 && day <= 25 {
-        Ok(day)
+    if (1..=25).contains(&day) {
+        Ok(DaySelection::Single(day))
     } else {
         Err(err_msg)
     }
@@ -44,20 +258,327 @@ fn validate_part(input: &str) -> Result<u8, String> {
     }
 }
 
+fn parse_param(input: &str) -> Result<(String, String), String> {
+    Params::parse_override(input).map_err(|err| err.to_string())
+}
+
+fn to_params(overrides: &[(String, String)]) -> Params {
+    overrides.iter().fold(Params::new(), |params, (key, value)| params.with(key, value))
+}
+
+// No limit for 0 seconds, and for days waiting for the user, who may take as long as they like
+fn time_limit(seconds: u64, waits_for_user: bool) -> Option<Duration> {
+    (seconds > 0 && !waits_for_user).then(|| Duration::from_secs(seconds))
+}
+
+fn is_interactive(year: &dyn AoCYear, day: u8) -> bool {
+    year.day_info(day).is_some_and(|info| info.interactive)
+}
+
 fn main() {
-    let input = fs::read_to_string("input.txt").expect("No file 'input.txt' found in project directory");
-    let args = Args::parse();
+    let cli = Cli::parse();
+    aoc_core::log::set_verbosity(cli.verbose);
+
+    match cli.command {
+        Some(Command::Bench(args)) => run_bench(args, &cli.input, cli.format),
+        Some(Command::New(args)) => run_new(args, &cli.input),
+        Some(Command::Status(args)) => run_status(args, &cli.input),
+        Some(Command::Submit(args)) => run_submit(args, &cli.input),
+        Some(Command::Fetch(args)) => run_fetch(args, &cli.input),
+        // Without a subcommand, the run arguments are required:
+        None => run(cli.run.unwrap(), &cli.input, cli.format),
+    }
+}
+
+fn run(args: RunArgs, input_args: &InputArgs, format: Format) {
+    // Year was checked when parsing the arguments:
+    let year = registry::get_year(args.year).unwrap();
 
-    let res = match args.year {
-        2019 => aoc_2019::solve(args.day, args.part, &input),
-        2025 => aoc_2025::solve(args.day, args.part, &input),
-           y => unreachable!("{} is not a valid year", y)
+    if input_args.input.is_some() && matches!(args.day, DaySelection::All) {
+        exit_with_error("--input can only be used for a single day");
+    }
+    if !args.params.is_empty() && matches!(args.day, DaySelection::All) {
+        exit_with_error("--param can only be used for a single day");
+    }
+    if args.visualize.is_some() && matches!(args.day, DaySelection::All) {
+        exit_with_error("--visualize can only be used for a single day");
+    }
+    let inputs = input_args.source();
+
+    let mut answers = None;
+    if args.check || args.record {
+        match AnswerStore::load(args.year) {
+            Ok(store) => answers = Some(store),
+            Err(err) => exit_with_error(err),
+        }
+    }
+
+    let jobs = args.jobs.map_or_else(|| thread::available_parallelism().map_or(1, |n| n.get()), usize::from);
+    let timeout = time_limit(args.timeout, false);
+    let record = |run: &PartRun| match &answers {
+        Some(answers) if args.check => Record::new(args.year, run).with_check(report::check(run, answers)),
+        _ => Record::new(args.year, run),
+    };
+
+    let single_day = matches!(args.day, DaySelection::Single(_));
+    let runs = match args.day {
+        DaySelection::Single(day) => {
+            let run = match inputs.read(args.year, day) {
+                Ok(input) => {
+                    let delay = Duration::from_millis(args.frame_delay);
+                    let frames = args.visualize.as_deref().map(|path| frame_sink(path, delay));
+                    let script = args.script.as_ref().map(|path| {
+                        fs::read_to_string(path).unwrap_or_else(|err| {
+                            exit_with_error(format!("could not open '{}': {}", path.display(), err))
+                        })
+                    });
+                    let timeout = time_limit(args.timeout, script.is_none() && is_interactive(year, day));
+
+                    // The solver runs on its own thread, so it gets its own handle of the frame sink
+                    let sink = frames.clone();
+                    let io = move || {
+                        let io = match &script {
+                            Some(script) => IoContext::new(Cursor::new(script.clone()), io::stdout()),
+                            None => IoContext::terminal(),
+                        };
+                        match &sink {
+                            Some(sink) => io.with_frames(Arc::clone(sink)),
+                            None => io,
+                        }
+                    };
+
+                    let run = runner::run_part(year, day, args.part, input, to_params(&args.params), timeout, io);
+                    // A solver that timed out may still be running, it must not show frames after the sink is finished
+                    let sink = frames.and_then(|sink| sink.lock().unwrap_or_else(PoisonError::into_inner).take());
+                    if let Some(Err(err)) = sink.map(|mut sink| sink.finish()) {
+                        exit_with_error(format!("could not write the frames: {}", err));
+                    }
+                    run
+                }
+                Err(err) => PartRun::not_run(day, args.part, Some(Err(err))),
+            };
+
+            match (&run.result, &answers) {
+                _ if format == Format::Json => print_output(output::to_json(&record(&run))),
+                _ if format == Format::Csv => print_output(format!("{}\n{}", output::CSV_HEADER, output::csv_row(&record(&run)))),
+                (_, Some(answers)) if args.check => report::print_check_row(&run, answers),
+                (Some(Ok(res @ AoCResult::Grid(_))), _) => println!("Day {:02} (part {}):\n{}", day, args.part, res),
+                (Some(Ok(res)), _) => println!("Day {:02} (part {}): {}", day, args.part, res),
+                (Some(Err(err)), _) => eprintln!("Error: {}", err),
+                (None, _) => {}
+            }
+
+            vec![run]
+        }
+        DaySelection::All if format == Format::Json => {
+            let runs = runner::run_all(year, &inputs, jobs, timeout, |_| {});
+            print_output(output::to_json(&runs.iter().map(record).collect::<Vec<_>>()));
+            runs
+        }
+        DaySelection::All if format == Format::Csv => {
+            print_output(output::CSV_HEADER);
+            runner::run_all(year, &inputs, jobs, timeout, |run| print_output(output::csv_row(&record(run))))
+        }
+        DaySelection::All if args.check => {
+            runner::run_all(year, &inputs, jobs, timeout, |run| report::print_check_row(run, answers.as_ref().unwrap()))
+        }
+        DaySelection::All => {
+            report::print_table_header();
+            let runs = runner::run_all(year, &inputs, jobs, timeout, report::print_table_row);
+            report::print_summary(&runs);
+            runs
+        }
+    };
+
+    // A missing input only leaves a gap when running all days, but a single day cannot be run without it
+    let mut failed = runs.iter().any(|r| r.failed() || (single_day && r.status() == runner::Status::NoInput));
+    if let Some(answers) = &mut answers {
+        if args.check && format == Format::Text {
+            failed |= report::print_check_summary(&runs, answers) > 0;
+        } else if args.check {
+            failed |= runs.iter().any(|r| report::check(r, answers) == report::Verdict::Fail);
+        }
+
+        if args.record {
+            let recorded = runs
+                .iter()
+                .filter(|r| matches!(&r.result, Some(Ok(res)) if answers.set(r.day, r.part, res)))
+                .count();
+
+            if let Err(err) = answers.save() {
+                exit_with_error(err);
+            }
+            // Keep the standard output parseable in the other formats
+            let message = format!("Recorded {} answer(s) in {}", recorded, AnswerStore::path(args.year).display());
+            if format == Format::Text {
+                println!("{}", message);
+            } else {
+                eprintln!("{}", message);
+            }
+        }
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
+
+// Live playback for "-", otherwise a file chosen by its extension
+// Shared with the solver thread, the sink is taken out of it to be finished
+fn frame_sink(path: &Path, delay: Duration) -> Arc<Mutex<Option<Box<dyn FrameSink + Send>>>> {
+    let sink: Box<dyn FrameSink + Send> = if path == Path::new("-") {
+        Box::new(TerminalPlayer::new(io::stdout(), delay))
+    } else {
+        let file = File::create(path).unwrap_or_else(|err| exit_with_error(format!("could not create '{}': {}", path.display(), err)));
+        if path.extension().is_some_and(|ext| ext == "cast") {
+            Box::new(CastWriter::new(BufWriter::new(file), delay))
+        } else {
+            Box::new(TextDump::new(BufWriter::new(file)))
+        }
+    };
+    Arc::new(Mutex::new(Some(sink)))
+}
+
+fn run_bench(args: BenchArgs, input_args: &InputArgs, format: Format) {
+    let year = registry::get_year(args.year).unwrap();
+
+    let params = to_params(&args.params);
+    let result = match bench::bench(year, &input_args.source(), args.day, args.part, args.runs, &params) {
+        Ok(result) => result,
+        Err(err) if format == Format::Text => exit_with_error(err),
+        Err(err) => {
+            print_bench_record(&BenchRecord::failed(args.year, args.day, args.part, args.runs, err), format);
+            process::exit(1);
+        }
+    };
+
+    if let Some(path) = &args.json
+        && let Err(err) = bench::write_json(&result, path)
+    {
+        exit_with_error(format!("could not write '{}': {}", path, err));
+    }
+
+    match format {
+        Format::Text => bench::print_result(&result),
+        _ => print_bench_record(&BenchRecord::from(result), format),
+    }
+}
+
+fn print_bench_record(record: &BenchRecord, format: Format) {
+    if format == Format::Csv {
+        print_output(format!("{}\n{}", output::bench_csv_header(), output::bench_csv_row(record)));
+    } else {
+        print_output(output::to_json(record));
+    }
+}
+
+// Prints a line of JSON or CSV output. It is often piped into tools like `head` that may stop
+// reading early, which ends the program quietly instead of panicking like println! does.
+fn print_output(line: impl Display) {
+    if let Err(err) = writeln!(io::stdout().lock(), "{}", line) {
+        if err.kind() == io::ErrorKind::BrokenPipe {
+            process::exit(0);
+        }
+        exit_with_error(err);
+    }
+}
+
+fn run_new(args: NewArgs, input_args: &InputArgs) {
+    let created = scaffold::new_day(Path::new("."), &input_args.input_dir, args.year, args.day)
+        .unwrap_or_else(|err| exit_with_error(err));
+
+    for path in created {
+        println!("Created {}", path.display());
+    }
+}
+
+fn run_status(args: StatusArgs, input_args: &InputArgs) {
+    let years = match args.year {
+        Some(year) => vec![registry::get_year(year).unwrap()],
+        None => registry::years(),
     };
 
-    match res {
-        AoCResult::Num(_) | AoCResult::Str(_) | AoCResult::PrintedToConsole => {
-            println!("Day {:02} (part {}): {}", args.day, args.part, res)
+    let inputs = input_args.source();
+    for year in years {
+        let answers = AnswerStore::load(year.year()).unwrap_or_else(|err| exit_with_error(err));
+        let status = status::year_status(year, &inputs, &answers, time_limit(args.timeout, false));
+        if args.markdown {
+            println!("{}", status::format_markdown(&status));
+        } else {
+            println!("{}", status::format_calendar(&status));
+        }
+    }
+
+    if args.markdown {
+        println!("{}", status::format_legend());
+    } else {
+        println!("Legend: {}", status::format_legend());
+    }
+}
+
+fn run_submit(args: SubmitArgs, input_args: &InputArgs) {
+    let year = registry::get_year(args.year).unwrap();
+    let client = client();
+
+    let answer = match args.answer {
+        Some(answer) => answer,
+        None => {
+            let input = input_args.source().read(args.year, args.day).unwrap_or_else(|err| exit_with_error(err));
+            let timeout = time_limit(args.timeout, is_interactive(year, args.day));
+            let run = runner::run_part(year, args.day, args.part, input, Params::new(), timeout, IoContext::terminal);
+            match run.result {
+                Some(Ok(res @ (AoCResult::Num(_) | AoCResult::Int(_) | AoCResult::Wide(_) | AoCResult::Str(_)))) => {
+                    res.to_string()
+                }
+                Some(Ok(res)) => exit_with_error(format!("cannot submit '{}', pass the answer with --answer", res)),
+                Some(Err(err)) => exit_with_error(err),
+                None => exit_with_error("part was not run"),
+            }
         }
-        _ => println!("Error: {}", res),
     };
+
+    let mut log = SubmissionLog::load(args.year).unwrap_or_else(|err| exit_with_error(err));
+    if let Some(reason) = log.refusal(args.day, args.part, &answer) {
+        exit_with_error(format!("not submitting {}: {}", answer, reason));
+    }
+
+    let outcome = client.submit(args.year, args.day, args.part, &answer).unwrap_or_else(|err| exit_with_error(err));
+    println!("Day {:02} (part {}): {} is {}", args.day, args.part, answer, outcome);
+
+    log.add(args.day, args.part, &answer, outcome.clone());
+    if let Err(err) = log.save() {
+        exit_with_error(err);
+    }
+
+    // Correct answers are known answers from now on
+    if outcome == Outcome::Correct {
+        let mut answers = AnswerStore::load(args.year).unwrap_or_else(|err| exit_with_error(err));
+        let result = answer.parse().map_or(AoCResult::Str(answer.clone()), AoCResult::Wide);
+        answers.set(args.day, args.part, &result);
+        if let Err(err) = answers.save() {
+            exit_with_error(err);
+        }
+    } else if outcome != Outcome::AlreadySolved {
+        process::exit(1);
+    }
+}
+
+fn run_fetch(args: FetchArgs, input_args: &InputArgs) {
+    let path = InputSource::Layout(input_args.input_dir.clone()).path(args.year, args.day);
+    match inputs::fetch(&client(), &path, args.year, args.day) {
+        Ok(true) => println!("Downloaded {}", path.display()),
+        Ok(false) => println!("{} already exists", path.display()),
+        Err(err) => exit_with_error(err),
+    }
+}
+
+fn client() -> client::Client {
+    config::Config::load()
+        .and_then(|config| client::Client::new(&config))
+        .unwrap_or_else(|err| exit_with_error(err))
+}
+
+fn exit_with_error(err: impl Display) -> ! {
+    eprintln!("Error: {}", err);
+    process::exit(1);
 }
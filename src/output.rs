@@ -0,0 +1,245 @@
+use crate::alloc_stats::AllocStats;
+use crate::bench::{BenchResult, Stats};
+use crate::report::Verdict;
+use crate::runner::{PartRun, Status};
+use clap::ValueEnum;
+use serde::Serialize;
+use std::fmt::Display;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+// Result of a single part in the machine-readable formats
+#[derive(Serialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub answer_type: Option<&'static str>,
+    pub status: &'static str,
+    // Solving only, parsing the input is reported separately
+    pub duration_ms: f64,
+    pub parse_ms: f64,
+    pub error: Option<String>,
+    // Only set with --check
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub check: Option<&'static str>,
+}
+
+pub const CSV_HEADER: &str = "year,day,part,answer,answer_type,status,duration_ms,parse_ms,error,check";
+
+impl Record {
+    pub fn new(year: u16, run: &PartRun) -> Self {
+        let (answer, error) = match &run.result {
+            Some(Ok(res)) => (Some(res), None),
+            Some(Err(err)) => (None, Some(err.to_string())),
+            None => (None, None),
+        };
+
+        Record {
+            year,
+            day: run.day,
+            part: run.part,
+            answer: answer.filter(|_| run.status() == Status::Ok).map(|res| res.to_string()),
+            answer_type: answer.map(|res| res.kind()),
+            status: status_key(run.status()),
+            duration_ms: millis(run.elapsed),
+            parse_ms: millis(run.parse_elapsed),
+            error,
+            check: None,
+        }
+    }
+
+    // Part that could not be run at all
+    pub fn failed(year: u16, day: u8, part: u8, err: impl Display) -> Self {
+        Record {
+            year,
+            day,
+            part,
+            answer: None,
+            answer_type: None,
+            status: status_key(Status::Error),
+            duration_ms: 0.0,
+            parse_ms: 0.0,
+            error: Some(err.to_string()),
+            check: None,
+        }
+    }
+
+    pub fn with_check(mut self, verdict: Verdict) -> Self {
+        self.check = Some(match verdict {
+            Verdict::Pass => "pass",
+            Verdict::Fail => "fail",
+            Verdict::Unchecked => "unchecked",
+        });
+        self
+    }
+
+    fn csv_fields(&self) -> Vec<String> {
+        vec![
+            self.year.to_string(),
+            self.day.to_string(),
+            self.part.to_string(),
+            self.answer.clone().unwrap_or_default(),
+            self.answer_type.unwrap_or_default().to_string(),
+            self.status.to_string(),
+            self.duration_ms.to_string(),
+            self.parse_ms.to_string(),
+            self.error.clone().unwrap_or_default(),
+            self.check.unwrap_or_default().to_string(),
+        ]
+    }
+}
+
+// Benchmark of a single part, the durations of the record are the medians
+#[derive(Serialize)]
+pub struct BenchRecord {
+    #[serde(flatten)]
+    pub record: Record,
+    pub runs: u32,
+    // Missing if the benchmark failed
+    pub parse: Option<Stats>,
+    pub solve: Option<Stats>,
+    // Only with the "alloc-stats" feature
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parse_alloc: Option<AllocStats>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub solve_alloc: Option<AllocStats>,
+}
+
+impl BenchRecord {
+    pub fn failed(year: u16, day: u8, part: u8, runs: u32, err: impl Display) -> Self {
+        BenchRecord {
+            record: Record::failed(year, day, part, err),
+            runs,
+            parse: None,
+            solve: None,
+            parse_alloc: None,
+            solve_alloc: None,
+        }
+    }
+}
+
+impl From<BenchResult> for BenchRecord {
+    fn from(result: BenchResult) -> Self {
+        BenchRecord {
+            record: Record {
+                year: result.year,
+                day: result.day,
+                part: result.part,
+                answer: Some(result.answer),
+                answer_type: Some(result.answer_type),
+                status: status_key(Status::Ok),
+                duration_ms: result.solve.median_ms,
+                parse_ms: result.parse.median_ms,
+                error: None,
+                check: None,
+            },
+            runs: result.runs,
+            parse: Some(result.parse),
+            solve: Some(result.solve),
+            parse_alloc: result.parse_alloc,
+            solve_alloc: result.solve_alloc,
+        }
+    }
+}
+
+pub fn csv_row(record: &Record) -> String {
+    csv_line(&record.csv_fields())
+}
+
+pub fn bench_csv_header() -> String {
+    let stats = ["parse", "solve"].map(|name| {
+        ["min", "median", "mean", "stddev"].map(|stat| format!("{}_{}_ms", name, stat)).join(",")
+    });
+    let allocs = ["parse", "solve"].map(|name| {
+        ["allocations", "alloc_bytes", "peak_bytes"].map(|stat| format!("{}_{}", name, stat)).join(",")
+    });
+    format!("{},runs,{},{}", CSV_HEADER, stats.join(","), allocs.join(","))
+}
+
+pub fn bench_csv_row(record: &BenchRecord) -> String {
+    let mut fields = record.record.csv_fields();
+    fields.push(record.runs.to_string());
+    for stats in [&record.parse, &record.solve] {
+        match stats {
+            Some(s) => fields.extend([s.min_ms, s.median_ms, s.mean_ms, s.stddev_ms].map(|ms| ms.to_string())),
+            None => fields.extend(vec![String::new(); 4]),
+        }
+    }
+    for alloc in [&record.parse_alloc, &record.solve_alloc] {
+        match alloc {
+            Some(a) => fields.extend([a.allocations, a.bytes, a.peak_bytes].map(|n| n.to_string())),
+            None => fields.extend(vec![String::new(); 3]),
+        }
+    }
+    csv_line(&fields)
+}
+
+pub fn to_json(value: &impl Serialize) -> String {
+    serde_json::to_string_pretty(value).expect("records are always serializable")
+}
+
+fn status_key(status: Status) -> &'static str {
+    match status {
+        Status::Ok => "ok",
+        Status::NotImplemented => "not_implemented",
+        Status::Skipped => "skipped",
+        Status::NoInput => "no_input",
+        Status::Error => "error",
+        Status::Timeout => "timeout",
+        Status::Panicked => "panicked",
+    }
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+// Quotes fields containing separators, quotes or line breaks (e.g. grid answers)
+fn csv_line(fields: &[String]) -> String {
+    fields
+        .iter()
+        .map(|f| {
+            if f.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", f.replace('"', "\"\""))
+            } else {
+                f.clone()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::{AoCError, AoCResult};
+
+    fn run(result: Option<Result<AoCResult, AoCError>>) -> PartRun {
+        PartRun { day: 8, part: 2, result, elapsed: Duration::from_millis(3), parse_elapsed: Duration::ZERO }
+    }
+
+    #[test]
+    fn csv_quotes_grid_answers() {
+        let record = Record::new(2019, &run(Some(Ok(AoCResult::Grid(vec!["#.".to_string(), ".#".to_string()])))));
+        assert_eq!(csv_row(&record), "2019,8,2,\"#.\n.#\",grid,ok,3,0,,");
+        assert_eq!(CSV_HEADER.split(',').count(), record.csv_fields().len());
+    }
+
+    #[test]
+    fn json_fields() {
+        let record = Record::new(2019, &run(Some(Err(AoCError::NoSolution)))).with_check(Verdict::Fail);
+        let json: serde_json::Value = serde_json::from_str(&to_json(&record)).unwrap();
+        assert_eq!(json["status"], "error");
+        assert_eq!(json["answer"], serde_json::Value::Null);
+        assert_eq!(json["error"], "No solution found");
+        assert_eq!(json["check"], "fail");
+    }
+}
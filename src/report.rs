@@ -0,0 +1,147 @@
+use crate::answers::AnswerStore;
+use crate::runner::{self, PartRun, Status};
+use aoc_core::AoCResult;
+use std::io::{self, IsTerminal};
+use std::time::Duration;
+
+const GREEN: &str = "\x1b[32m";
+const RED: &str = "\x1b[31m";
+const YELLOW: &str = "\x1b[33m";
+const RESET: &str = "\x1b[0m";
+
+// Colors the text if it is printed to a terminal
+fn paint(color: &str, text: impl std::fmt::Display) -> String {
+    if io::stdout().is_terminal() {
+        format!("{}{}{}", color, text, RESET)
+    } else {
+        text.to_string()
+    }
+}
+
+pub fn print_table_header() {
+    println!("{:>3}  {:>4}  {:<20}  {:<15}  {:>12}  {:>12}", "Day", "Part", "Answer", "Status", "Parse", "Time");
+    println!("{}", "-".repeat(3 + 4 + 20 + 15 + 12 + 12 + 5 * 2));
+}
+
+pub fn print_table_row(run: &PartRun) {
+    let answer = match &run.result {
+        // Multi-line answers do not fit into the table, they are printed below the row
+        Some(Ok(AoCResult::Grid(_))) => "(see below)".to_string(),
+        Some(Ok(res)) if run.status() == Status::Ok => res.to_string(),
+        _ => String::new(),
+    };
+
+    let status = match run.status() {
+        Status::Ok => "Ok",
+        Status::NotImplemented => "Not implemented",
+        Status::Skipped => "Skipped",
+        Status::NoInput => "No input",
+        Status::Error => "Error",
+        Status::Timeout => "Timeout",
+        Status::Panicked => "Panicked",
+    };
+
+    let (parse_elapsed, elapsed) = if run.status() == Status::Ok {
+        (format_duration(run.parse_elapsed), format_duration(run.elapsed))
+    } else {
+        (String::new(), String::new())
+    };
+    print!("{:>3}  {:>4}  {:<20}  {:<15}  {:>12}  {:>12}", run.day, run.part, answer, status, parse_elapsed, elapsed);
+
+    // Error messages are usually longer than the status column, so print them last:
+    if let Some(Err(err)) = &run.result {
+        print!("  {}", err);
+    }
+    println!();
+
+    if let Some(Ok(AoCResult::Grid(lines))) = &run.result {
+        for line in lines {
+            println!("{:11}{}", "", line);
+        }
+    }
+}
+
+pub fn print_summary(runs: &[PartRun]) {
+    let count = |status| runs.iter().filter(|r| r.status() == status).count();
+    let total = runner::total_time(runs);
+
+    println!();
+    println!(
+        "{} ok, {} not implemented, {} skipped, {} without input, {} failed, {} timed out, {} panicked (total time: {})",
+        count(Status::Ok),
+        count(Status::NotImplemented),
+        count(Status::Skipped),
+        count(Status::NoInput),
+        count(Status::Error),
+        count(Status::Timeout),
+        count(Status::Panicked),
+        format_duration(total)
+    );
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let micros = duration.as_micros();
+    if micros < 1000 {
+        format!("{} µs", micros)
+    } else if micros < 1_000_000 {
+        format!("{:.2} ms", micros as f64 / 1000.0)
+    } else {
+        format!("{:.2} s", duration.as_secs_f64())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail,
+    // No known answer or nothing to compare
+    Unchecked,
+}
+
+pub fn check(run: &PartRun, answers: &AnswerStore) -> Verdict {
+    match (&run.result, answers.get(run.day, run.part)) {
+        _ if run.status() == Status::NoInput => Verdict::Unchecked,
+        (Some(Err(_)), _) => Verdict::Fail,
+        (Some(Ok(res)), Some(expected)) if run.status() == Status::Ok => {
+            if res.to_string() == expected { Verdict::Pass } else { Verdict::Fail }
+        }
+        _ => Verdict::Unchecked,
+    }
+}
+
+pub fn print_check_row(run: &PartRun, answers: &AnswerStore) {
+    let (color, label) = match check(run, answers) {
+        Verdict::Pass => (GREEN, "PASS"),
+        Verdict::Fail => (RED, "FAIL"),
+        Verdict::Unchecked => (YELLOW, "----"),
+    };
+
+    let details = match (&run.result, answers.get(run.day, run.part)) {
+        (Some(Err(err)), _) if run.status() == Status::NoInput => err.to_string(),
+        (Some(Err(err)), _) => format!("Error: {}", err),
+        (Some(Ok(res)), Some(expected)) if res.to_string() != expected && run.status() == Status::Ok => {
+            format!("{} (expected {})", res, expected)
+        }
+        (Some(Ok(res)), None) if run.status() == Status::Ok => format!("{} (no known answer)", res),
+        (Some(Ok(res)), _) => res.to_string(),
+        (None, _) => "Skipped".to_string(),
+    };
+
+    println!("Day {:02} (part {}): {}  {}", run.day, run.part, paint(color, label), details);
+}
+
+// Prints the check results, returns the number of failed parts
+pub fn print_check_summary(runs: &[PartRun], answers: &AnswerStore) -> usize {
+    let count = |verdict| runs.iter().filter(|r| check(r, answers) == verdict).count();
+    let failed = count(Verdict::Fail);
+
+    println!();
+    println!(
+        "{}, {}, {} unchecked",
+        paint(GREEN, format!("{} passed", count(Verdict::Pass))),
+        if failed > 0 { paint(RED, format!("{} failed", failed)) } else { "0 failed".to_string() },
+        count(Verdict::Unchecked)
+    );
+
+    failed
+}
@@ -0,0 +1,373 @@
+use crate::inputs::InputSource;
+use aoc_core::{AoCError, AoCResult, AoCYear, IoContext, Params, PuzzleInput};
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Once, mpsc};
+use std::thread;
+use std::time::{Duration, Instant};
+
+// Same as the main thread, some solvers recurse deeply
+const SOLVER_STACK_SIZE: usize = 8 * 1024 * 1024;
+// Panics of threads with this name are reported as results instead of being printed
+const SOLVER_THREAD: &str = "solver";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    NotImplemented,
+    Skipped,
+    NoInput,
+    Error,
+    Timeout,
+    Panicked,
+}
+
+// Result of running a single part of a day
+pub struct PartRun {
+    pub day: u8,
+    pub part: u8,
+    // None if the part was not run at all
+    pub result: Option<Result<AoCResult, AoCError>>,
+    // Solving only, the input is parsed once per day and shared by both parts
+    pub elapsed: Duration,
+    pub parse_elapsed: Duration,
+}
+
+impl PartRun {
+    pub fn status(&self) -> Status {
+        match &self.result {
+            Some(Ok(AoCResult::NotImplemented)) => Status::NotImplemented,
+            Some(Ok(_)) => Status::Ok,
+            Some(Err(AoCError::Timeout(_))) => Status::Timeout,
+            Some(Err(AoCError::Panicked(_))) => Status::Panicked,
+            Some(Err(AoCError::MissingInput(_))) => Status::NoInput,
+            Some(Err(_)) => Status::Error,
+            None => Status::Skipped,
+        }
+    }
+
+    // Part that was not solved, e.g. because its input is missing
+    pub fn not_run(day: u8, part: u8, result: Option<Result<AoCResult, AoCError>>) -> Self {
+        PartRun { day, part, result, elapsed: Duration::ZERO, parse_elapsed: Duration::ZERO }
+    }
+
+    // Part did not finish properly (it failed, timed out or panicked). A missing input is a gap
+    // like a day that is not implemented yet, not a failure.
+    pub fn failed(&self) -> bool {
+        matches!(self.status(), Status::Error | Status::Timeout | Status::Panicked)
+    }
+}
+
+// Parses the input of the day once and solves the given parts from it.
+//
+// Parsing and each part are stopped after `timeout` (if given) and panics are caught, see
+// `guarded`. A solver that timed out keeps running, so each part gets its own IoContext from `io`,
+// which is cancelled on timeout.
+pub fn run_parts(
+    year: &dyn AoCYear,
+    day: u8,
+    parts: &[u8],
+    input: PuzzleInput,
+    params: Params,
+    timeout: Option<Duration>,
+    io: impl Fn() -> IoContext<'static> + Send + Sync + 'static,
+) -> Vec<PartRun> {
+    let Some(solver) = year.get_day(day) else {
+        return parts.iter().map(|&part| PartRun::not_run(day, part, Some(Err(AoCError::InvalidDay(day))))).collect();
+    };
+
+    let (parsed, parse_elapsed) = guarded(timeout, move |_| solver.parse(&input));
+    let parsed = parsed.map(Arc::new);
+    let (params, io) = (Arc::new(params), Arc::new(io));
+    parts
+        .iter()
+        .map(|&part| {
+            let (result, elapsed) = match &parsed {
+                Ok(parsed) => {
+                    let (parsed, params, io) = (Arc::clone(parsed), Arc::clone(&params), Arc::clone(&io));
+                    guarded(timeout, move |cancelled| {
+                        solver.solve_parsed(part, &parsed, &params, &mut io().cancelled_by(cancelled))
+                    })
+                }
+                Err(err) => (Err(err.clone()), Duration::ZERO),
+            };
+            PartRun { day, part, result: Some(result), elapsed, parse_elapsed }
+        })
+        .collect()
+}
+
+pub fn run_part(
+    year: &dyn AoCYear,
+    day: u8,
+    part: u8,
+    input: PuzzleInput,
+    params: Params,
+    timeout: Option<Duration>,
+    io: impl Fn() -> IoContext<'static> + Send + Sync + 'static,
+) -> PartRun {
+    run_parts(year, day, &[part], input, params, timeout, io).remove(0)
+}
+
+// Runs both parts of every registered day of the year on up to `jobs` threads (one day per job,
+// so that its input is parsed only once). `on_finished` is called after each part in order of
+// the days, regardless of which job finishes first. Days are run by `run_parts`, so a single
+// broken day cannot stop the others.
+pub fn run_all(
+    year: &dyn AoCYear,
+    inputs: &InputSource,
+    jobs: usize,
+    timeout: Option<Duration>,
+    mut on_finished: impl FnMut(&PartRun),
+) -> Vec<PartRun> {
+    let days = year.days();
+    let mut runs = vec![];
+    let mut finish = |day_runs: Vec<PartRun>| {
+        for run in day_runs {
+            on_finished(&run);
+            runs.push(run);
+        }
+    };
+
+    if jobs <= 1 {
+        days.iter().for_each(|&day| finish(run_day(year, inputs, day, timeout)));
+        return runs;
+    }
+
+    let next_job = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(days.len()) {
+            let (sender, next_job, days) = (sender.clone(), &next_job, &days);
+            scope.spawn(move || {
+                while let Some(&day) = days.get(next_job.fetch_add(1, Ordering::Relaxed)) {
+                    if sender.send((day, run_day(year, inputs, day, timeout))).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        drop(sender);
+
+        // Days finishing early wait until all days before them are reported
+        let mut pending = BTreeMap::new();
+        let mut next_day = days.iter().peekable();
+        for (day, day_runs) in receiver {
+            pending.insert(day, day_runs);
+            while let Some(day_runs) = next_day.peek().and_then(|day| pending.remove(*day)) {
+                finish(day_runs);
+                next_day.next();
+            }
+        }
+    });
+
+    runs
+}
+
+// Both parts of a single day, as part of `run_all`
+fn run_day(year: &dyn AoCYear, inputs: &InputSource, day: u8, timeout: Option<Duration>) -> Vec<PartRun> {
+    // Interactive days would wait for user input forever, so their input is not even read (or downloaded)
+    if year.day_info(day).is_some_and(|info| info.interactive) {
+        return vec![PartRun::not_run(day, 1, None), PartRun::not_run(day, 2, None)];
+    }
+
+    match inputs.read(year.year(), day) {
+        Ok(input) => run_parts(year, day, &[1, 2], input, Params::new(), timeout, IoContext::silent),
+        Err(err) => [1, 2].map(|part| PartRun::not_run(day, part, Some(Err(err.clone())))).into(),
+    }
+}
+
+// Runs the function on its own thread, giving up on it after the timeout. A solver cannot be
+// stopped from the outside: the flag passed to `f` is set on timeout, but a solver that never
+// checks it (e.g. through IoContext::is_cancelled) keeps running in the background until the
+// process exits. Such leaked threads still use a CPU, which may slow down the other jobs.
+fn guarded<T: Send + 'static>(
+    timeout: Option<Duration>,
+    f: impl FnOnce(Arc<AtomicBool>) -> Result<T, AoCError> + Send + 'static,
+) -> (Result<T, AoCError>, Duration) {
+    static QUIET_PANICS: Once = Once::new();
+    QUIET_PANICS.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if thread::current().name() != Some(SOLVER_THREAD) {
+                default_hook(info);
+            }
+        }));
+    });
+
+    let cancelled = Arc::new(AtomicBool::new(false));
+    let flag = Arc::clone(&cancelled);
+    let (sender, receiver) = mpsc::channel();
+    thread::Builder::new()
+        .name(SOLVER_THREAD.to_string())
+        .stack_size(SOLVER_STACK_SIZE)
+        .spawn(move || {
+            let start = Instant::now();
+            let result = panic::catch_unwind(AssertUnwindSafe(|| f(flag)))
+                .unwrap_or_else(|payload| Err(AoCError::Panicked(panic_message(payload.as_ref()))));
+            // The receiver is gone if the time limit was exceeded
+            let _ = sender.send((result, start.elapsed()));
+        })
+        .expect("failed to start solver thread");
+
+    match timeout {
+        Some(limit) => receiver.recv_timeout(limit).unwrap_or_else(|_| {
+            cancelled.store(true, Ordering::Relaxed);
+            (Err(AoCError::Timeout(limit)), limit)
+        }),
+        None => receiver.recv().expect("solver thread reports its result"),
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    match (payload.downcast_ref::<&str>(), payload.downcast_ref::<String>()) {
+        (Some(msg), _) => msg.to_string(),
+        (_, Some(msg)) => msg.clone(),
+        _ => "(no message)".to_string(),
+    }
+}
+
+// Time spent on all runs, counting the shared parsing only once per day
+pub fn total_time(runs: &[PartRun]) -> Duration {
+    runs.iter()
+        .enumerate()
+        .map(|(i, run)| {
+            let first_of_day = i == 0 || runs[i - 1].day != run.day;
+            run.elapsed + if first_of_day { run.parse_elapsed } else { Duration::ZERO }
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::Client;
+    use crate::config::Config;
+    use std::net::TcpListener;
+    use std::path::PathBuf;
+    use std::{env, fs};
+
+    // Days of a year without a crate, each sleeping for the milliseconds given in its input
+    const STUB_YEAR: u16 = 1999;
+
+    struct StubYear;
+
+    impl AoCYear for StubYear {
+        fn year(&self) -> u16 {
+            STUB_YEAR
+        }
+    }
+
+    macro_rules! stub_day {
+        ($module:ident, $struct_name:ident, $day:literal $(, $interactive:ident)?) => {
+            mod $module {
+                use aoc_core::{AoCError, AoCResult};
+
+                aoc_core::make_day!($struct_name, 1999, $day $(, $interactive)?);
+
+                pub fn solve_part1(input: &str) -> Result<AoCResult, AoCError> {
+                    super::sleep(input)
+                }
+
+                pub fn solve_part2(input: &str) -> Result<AoCResult, AoCError> {
+                    super::sleep(input)
+                }
+            }
+        };
+    }
+
+    stub_day!(day01, Day01, 1);
+    stub_day!(day02, Day02, 2);
+    stub_day!(day03, Day03, 3);
+    stub_day!(day04, Day04, 4);
+    stub_day!(day05, Day05, 5, interactive);
+
+    fn sleep(input: &str) -> Result<AoCResult, AoCError> {
+        let millis = input.trim().parse().map_err(|_| AoCError::parse_error(1, 1, "not a number"))?;
+        thread::sleep(Duration::from_millis(millis));
+        Ok(AoCResult::Num(millis))
+    }
+
+    #[test]
+    fn parallel_runs_keep_order() {
+        // Later days finish first, but are reported only after the days before them
+        let sleeps = [150, 10, 80, 0];
+        let dir = env::temp_dir().join(format!("aoc_rust_runner_{}", std::process::id()));
+        fs::create_dir_all(dir.join(STUB_YEAR.to_string())).unwrap();
+        for (day, millis) in sleeps.iter().enumerate() {
+            fs::write(InputSource::Layout(dir.clone()).path(STUB_YEAR, day as u8 + 1), millis.to_string()).unwrap();
+        }
+        let inputs = InputSource::Layout(dir.clone());
+
+        let summary = |run: &PartRun| (run.day, run.part, run.result.clone());
+        let mut reported = vec![];
+        let runs = run_all(&StubYear, &inputs, 4, None, |run| reported.push(summary(run)));
+
+        let mut expected: Vec<_> = (1..=4)
+            .flat_map(|day| [1, 2].map(|part| (day, part, Some(Ok(AoCResult::Num(sleeps[day as usize - 1]))))))
+            .collect();
+        expected.extend([(5, 1, None), (5, 2, None)]);
+        assert_eq!(reported, expected);
+        assert_eq!(runs.iter().map(summary).collect::<Vec<_>>(), expected);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn skip_interactive_days() {
+        // The input of an interactive day must not be downloaded, as it is not used
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let config = Config { session: Some("abc".to_string()), base_url: Some(base_url) };
+        let inputs = InputSource::Fetch(PathBuf::from("no_such_dir"), Client::new(&config).unwrap());
+
+        let runs = run_day(&StubYear, &inputs, 5, None);
+        assert!(runs.iter().all(|run| run.status() == Status::Skipped));
+        listener.set_nonblocking(true).unwrap();
+        assert!(listener.accept().is_err_and(|err| err.kind() == std::io::ErrorKind::WouldBlock));
+    }
+
+    #[test]
+    fn missing_inputs_are_gaps() {
+        let dir = env::temp_dir().join(format!("aoc_rust_runner_gaps_{}", std::process::id()));
+        fs::create_dir_all(dir.join(STUB_YEAR.to_string())).unwrap();
+        fs::write(InputSource::Layout(dir.clone()).path(STUB_YEAR, 2), "soon").unwrap();
+        let inputs = InputSource::Layout(dir.clone());
+
+        let runs = run_day(&StubYear, &inputs, 1, None);
+        assert!(runs.iter().all(|run| run.status() == Status::NoInput && !run.failed()));
+        let runs = run_day(&StubYear, &inputs, 2, None);
+        assert!(runs.iter().all(|run| run.status() == Status::Error && run.failed()));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn stop_broken_solvers() {
+        let limit = Duration::from_millis(20);
+        let (result, elapsed) = guarded(Some(limit), |_| {
+            thread::sleep(Duration::from_secs(2));
+            Ok(())
+        });
+        assert_eq!(result, Err(AoCError::Timeout(limit)));
+        assert_eq!(elapsed, limit);
+
+        let (result, _) = guarded(None, |_| -> Result<(), AoCError> { panic!("day {} is broken", 14) });
+        assert_eq!(result, Err(AoCError::Panicked("day 14 is broken".to_string())));
+
+        assert_eq!(guarded(Some(Duration::from_secs(10)), |_| Ok(42)).0, Ok(42));
+
+        // A solver checking the flag can stop once nobody waits for it anymore
+        let (sender, receiver) = mpsc::channel();
+        let (result, _) = guarded(Some(limit), move |cancelled| {
+            while !cancelled.load(Ordering::Relaxed) {
+                thread::sleep(Duration::from_millis(1));
+            }
+            sender.send(()).unwrap();
+            Ok(())
+        });
+        assert_eq!(result, Err(AoCError::Timeout(limit)));
+        assert!(receiver.recv_timeout(Duration::from_secs(10)).is_ok());
+    }
+}
@@ -0,0 +1,191 @@
+use crate::status;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// Creates the module of a new day from a template and registers it in days.rs, together with
+// placeholders for its input and example. If the year crate does not exist yet, it is created
+// and added to the workspace. Returns the created files.
+pub fn new_day(root: &Path, input_dir: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>, String> {
+    if day > status::days_in_year(year) {
+        return Err(format!("{} only has {} days", year, status::days_in_year(year)));
+    }
+
+    let crate_dir = root.join(format!("aoc_{}", year));
+    let day_file = crate_dir.join("src").join("days").join(format!("day{:02}.rs", day));
+    if day_file.exists() {
+        return Err(format!("{} already exists", day_file.display()));
+    }
+
+    let mut created = vec![];
+    if !crate_dir.exists() {
+        created.extend(new_year(root, year)?);
+    }
+
+    write_new(&day_file, &day_template(year, day), &mut created)?;
+    register_module(&crate_dir.join("src").join("days.rs"), &format!("day{:02}", day))?;
+
+    write_new(&input_dir.join(year.to_string()).join(format!("day{:02}.txt", day)), "", &mut created)?;
+    write_new(
+        &root.join("examples").join(year.to_string()).join(format!("day{:02}", day)).join("example1.txt"),
+        EXAMPLE_TEMPLATE,
+        &mut created,
+    )?;
+
+    Ok(created)
+}
+
+// Year crate skeleton like aoc_2025, linked into the binary by adding it to Cargo.toml
+fn new_year(root: &Path, year: u16) -> Result<Vec<PathBuf>, String> {
+    let crate_dir = root.join(format!("aoc_{}", year));
+    let mut created = vec![];
+
+    let manifest = format!(
+        "[package]\nname = \"aoc_{year}\"\nversion = \"0.1.0\"\nedition = \"2024\"\n\n\
+         [dependencies]\nitertools = \"0.14.0\"\naoc_core = {{ path = \"../aoc_core\" }}\n\n\
+         [build-dependencies]\naoc_core = {{ path = \"../aoc_core\" }}\n"
+    );
+    write_new(&crate_dir.join("Cargo.toml"), &manifest, &mut created)?;
+
+    let build_script = format!(
+        "// Generates a test for every example in examples/{year}, see aoc_core::examples\n\
+         fn main() {{\n    aoc_core::examples::generate_tests({year}, \"../examples/{year}\");\n}}\n"
+    );
+    write_new(&crate_dir.join("build.rs"), &build_script, &mut created)?;
+
+    let lib = format!(
+        "pub mod days;\n\naoc_core::make_year!(Year{year}, {year});\n\n\
+         // One test per file in examples/{year}, see build.rs\n\
+         #[cfg(test)]\nmod examples {{\n    include!(concat!(env!(\"OUT_DIR\"), \"/example_tests.rs\"));\n}}\n"
+    );
+    write_new(&crate_dir.join("src").join("lib.rs"), &lib, &mut created)?;
+    write_new(&crate_dir.join("src").join("days.rs"), "", &mut created)?;
+
+    add_dependency(&root.join("Cargo.toml"), &format!("aoc_{year} = {{ path = \"./aoc_{year}\" }}"))?;
+    Ok(created)
+}
+
+fn day_template(year: u16, day: u8) -> String {
+    format!(
+        "use aoc_core::{{AoCError, AoCResult, PuzzleInput}};\n\n\
+         aoc_core::make_day!(Day{day:02}, {year}, {day});\n\n\
+         pub fn solve_part1(_input: &PuzzleInput) -> Result<AoCResult, AoCError> {{\n    Ok(AoCResult::NotImplemented)\n}}\n\n\
+         pub fn solve_part2(_input: &PuzzleInput) -> Result<AoCResult, AoCError> {{\n    Ok(AoCResult::NotImplemented)\n}}\n\n\
+         #[cfg(test)]\nmod tests {{\n    // Puzzle examples go to examples/{year}/day{day:02}\n}}\n"
+    )
+}
+
+// Ignored until the example has been filled in, see aoc_core::examples for the format
+const EXAMPLE_TEMPLATE: &str = "ignore: example not filled in yet\npart1: \n---\n";
+
+fn write_new(path: &Path, content: &str, created: &mut Vec<PathBuf>) -> Result<(), String> {
+    if path.exists() {
+        return Ok(());
+    }
+
+    fs::create_dir_all(path.parent().unwrap())
+        .and_then(|_| fs::write(path, content))
+        .map_err(|err| format!("{}: {}", path.display(), err))?;
+    created.push(path.to_path_buf());
+    Ok(())
+}
+
+// Inserts `pub mod <name>;` before the first module that sorts after it (or after the last
+// module), leaving all other lines and the line endings of the file as they are
+fn register_module(days_file: &Path, name: &str) -> Result<(), String> {
+    let content = fs::read_to_string(days_file).map_err(|err| format!("{}: {}", days_file.display(), err))?;
+    let line_ending = if content.contains("\r\n") { "\r\n" } else { "\n" };
+    let trailing_newline = content.is_empty() || content.ends_with('\n');
+
+    let mut lines: Vec<&str> = content.lines().collect();
+    let module = format!("pub mod {};", name);
+    if lines.contains(&module.as_str()) {
+        return Ok(());
+    }
+    let position = lines
+        .iter()
+        .position(|l| l.starts_with("pub mod ") && *l > module.as_str())
+        .or_else(|| lines.iter().rposition(|l| l.starts_with("pub mod ")).map(|i| i + 1))
+        .unwrap_or(lines.len());
+    lines.insert(position, &module);
+
+    let mut content = lines.join(line_ending);
+    if trailing_newline {
+        content.push_str(line_ending);
+    }
+    fs::write(days_file, content).map_err(|err| format!("{}: {}", days_file.display(), err))
+}
+
+// Appends the year crate to the dependencies of the binary, see build.rs
+fn add_dependency(manifest: &Path, dependency: &str) -> Result<(), String> {
+    let content = fs::read_to_string(manifest).map_err(|err| format!("{}: {}", manifest.display(), err))?;
+    let line_ending = if content.contains("\r\n") { "\r\n" } else { "\n" };
+    let trailing_newline = content.is_empty() || content.ends_with('\n');
+
+    let mut lines: Vec<&str> = content.lines().collect();
+    let position = lines
+        .iter()
+        .rposition(|l| l.starts_with("aoc_"))
+        .map(|i| i + 1)
+        .unwrap_or(lines.len());
+    lines.insert(position, dependency);
+
+    let mut content = lines.join(line_ending);
+    if trailing_newline {
+        content.push_str(line_ending);
+    }
+    fs::write(manifest, content).map_err(|err| format!("{}: {}", manifest.display(), err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn new_year_and_day() {
+        let root = env::temp_dir().join(format!("aoc_rust_scaffold_{}", std::process::id()));
+        fs::create_dir_all(&root).unwrap();
+        fs::write(root.join("Cargo.toml"), "[dependencies]\r\naoc_core = { path = \"./aoc_core\" }\r\nclap = \"4\"\r\n").unwrap();
+
+        new_day(&root, &root.join("inputs"), 2030, 7).unwrap();
+        let created = new_day(&root, &root.join("inputs"), 2030, 3).unwrap();
+        assert_eq!(created.len(), 3);
+        assert!(new_day(&root, &root.join("inputs"), 2030, 3).is_err());
+
+        let days = fs::read_to_string(root.join("aoc_2030/src/days.rs")).unwrap();
+        assert_eq!(days, "pub mod day03;\npub mod day07;\n");
+        let manifest = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+        assert_eq!(
+            manifest,
+            "[dependencies]\r\naoc_core = { path = \"./aoc_core\" }\r\naoc_2030 = { path = \"./aoc_2030\" }\r\nclap = \"4\"\r\n"
+        );
+        assert!(root.join("inputs/2030/day03.txt").exists());
+        assert!(root.join("examples/2030/day03/example1.txt").exists());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn register_module_keeps_other_lines() {
+        let days_file = env::temp_dir().join(format!("aoc_rust_days_{}.rs", std::process::id()));
+        fs::write(&days_file, "// Solved days\r\npub mod day09;\r\npub mod day01;\r\n\r\npub mod day12;\r\n").unwrap();
+
+        register_module(&days_file, "day10").unwrap();
+        register_module(&days_file, "day13").unwrap();
+        register_module(&days_file, "day10").unwrap();
+        let days = fs::read_to_string(&days_file).unwrap();
+        assert_eq!(
+            days,
+            "// Solved days\r\npub mod day09;\r\npub mod day01;\r\n\r\npub mod day10;\r\npub mod day12;\r\npub mod day13;\r\n"
+        );
+
+        fs::remove_file(&days_file).unwrap();
+    }
+
+    #[test]
+    fn day_beyond_end_of_year() {
+        let root = env::temp_dir().join(format!("aoc_rust_scaffold_late_{}", std::process::id()));
+        assert!(new_day(&root, &root.join("inputs"), 2025, 13).is_err());
+        assert!(!root.exists());
+    }
+}
@@ -0,0 +1,185 @@
+use crate::answers::AnswerStore;
+use crate::inputs::InputSource;
+use crate::runner::{self, PartRun};
+use aoc_core::{AoCError, AoCResult, AoCYear, IoContext, Params};
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartState {
+    // Day is not implemented at all
+    Missing,
+    // Answer is known from the answers store
+    Verified,
+    // Answer found, but not in the answers store
+    Solved,
+    NotImplemented,
+    // Answer has to be read from the console output or needs user input
+    Console,
+    // Input of the day is not available, so the part could not be run
+    NoInput,
+    // Solver failed, timed out or panicked
+    Failed,
+}
+
+impl PartState {
+    const ALL: [PartState; 7] = [
+        PartState::Verified,
+        PartState::Solved,
+        PartState::NotImplemented,
+        PartState::Console,
+        PartState::NoInput,
+        PartState::Failed,
+        PartState::Missing,
+    ];
+
+    fn symbol(self) -> char {
+        match self {
+            PartState::Missing => '·',
+            PartState::Verified => '★',
+            PartState::Solved => '☆',
+            PartState::NotImplemented => '○',
+            PartState::Console => '▣',
+            PartState::NoInput => '?',
+            PartState::Failed => '✗',
+        }
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            PartState::Missing => "missing",
+            PartState::Verified => "verified",
+            PartState::Solved => "solved",
+            PartState::NotImplemented => "not implemented",
+            PartState::Console => "console",
+            PartState::NoInput => "no input",
+            PartState::Failed => "failed",
+        }
+    }
+}
+
+pub struct YearStatus {
+    pub year: u16,
+    // State of both parts, by day
+    pub days: Vec<[PartState; 2]>,
+}
+
+impl YearStatus {
+    fn count(&self, state: PartState) -> usize {
+        self.days.iter().flatten().filter(|&&s| s == state).count()
+    }
+}
+
+// Since 2025, there are only 12 puzzles per year
+pub fn days_in_year(year: u16) -> u8 {
+    if year >= 2025 { 12 } else { 25 }
+}
+
+// Parts with a known answer count as verified, all others are run to find out their state. Like
+// with "all", each part is stopped after `timeout` (if given).
+pub fn year_status(
+    year: &dyn AoCYear,
+    inputs: &InputSource,
+    answers: &AnswerStore,
+    timeout: Option<Duration>,
+) -> YearStatus {
+    let days = (1..=days_in_year(year.year()))
+        .map(|day| {
+            let Some(info) = year.day_info(day) else {
+                return [PartState::Missing; 2];
+            };
+
+            let unverified: Vec<u8> = (1..=2).filter(|&part| answers.get(day, part).is_none()).collect();
+            let (runs, missing_input) = match inputs.read(year.year(), day) {
+                // Interactive days get no user input, so they stop as soon as they ask for it
+                Ok(input) if !unverified.is_empty() => {
+                    (runner::run_parts(year, day, &unverified, input, Params::new(), timeout, IoContext::silent), false)
+                }
+                Err(AoCError::MissingInput(_)) => (vec![], true),
+                _ => (vec![], false),
+            };
+
+            [1, 2].map(|part| match runs.iter().find(|r| r.part == part) {
+                Some(run) => state_of(run, info.interactive),
+                None if answers.get(day, part).is_some() => PartState::Verified,
+                None if missing_input => PartState::NoInput,
+                None => PartState::Failed,
+            })
+        })
+        .collect();
+
+    YearStatus { year: year.year(), days }
+}
+
+fn state_of(run: &PartRun, interactive: bool) -> PartState {
+    match &run.result {
+        Some(Ok(AoCResult::NotImplemented)) => PartState::NotImplemented,
+        Some(Ok(AoCResult::PrintedToConsole)) => PartState::Console,
+        Some(Ok(_)) => PartState::Solved,
+        _ if interactive => PartState::Console,
+        _ => PartState::Failed,
+    }
+}
+
+// Calendar with five days per row, e.g. "01 ★★  02 ★☆"
+pub fn format_calendar(status: &YearStatus) -> String {
+    let mut text = format!("{}: {} stars\n", status.year, status.count(PartState::Verified));
+    for (week, days) in status.days.chunks(5).enumerate() {
+        let cells: Vec<String> = days.iter().enumerate().map(|(i, parts)| cell(week * 5 + i + 1, parts)).collect();
+        text += &format!("  {}\n", cells.join("  "));
+    }
+    text
+}
+
+// Same calendar as a markdown table
+pub fn format_markdown(status: &YearStatus) -> String {
+    let mut text = format!("### {} ({} stars)\n\n", status.year, status.count(PartState::Verified));
+    text += "|   |   |   |   |   |\n|---|---|---|---|---|\n";
+    for (week, days) in status.days.chunks(5).enumerate() {
+        let cells: Vec<String> = days.iter().enumerate().map(|(i, parts)| cell(week * 5 + i + 1, parts)).collect();
+        text += &format!("| {} |\n", cells.join(" | "));
+    }
+    text
+}
+
+pub fn format_legend() -> String {
+    PartState::ALL
+        .iter()
+        .map(|s| format!("{} {}", s.symbol(), s.description()))
+        .collect::<Vec<_>>()
+        .join("  ")
+}
+
+fn cell(day: usize, parts: &[PartState; 2]) -> String {
+    format!("{:02} {}{}", day, parts[0].symbol(), parts[1].symbol())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status() -> YearStatus {
+        let mut days = vec![[PartState::Missing; 2]; 7];
+        days[0] = [PartState::Verified; 2];
+        days[1] = [PartState::Verified, PartState::NotImplemented];
+        days[5] = [PartState::Solved, PartState::Console];
+        days[6] = [PartState::NoInput, PartState::Failed];
+        YearStatus { year: 2025, days }
+    }
+
+    #[test]
+    fn calendar() {
+        assert_eq!(
+            format_calendar(&status()),
+            "2025: 3 stars\n  01 ★★  02 ★○  03 ··  04 ··  05 ··\n  06 ☆▣  07 ?✗\n"
+        );
+    }
+
+    #[test]
+    fn markdown() {
+        assert_eq!(
+            format_markdown(&status()),
+            "### 2025 (3 stars)\n\n|   |   |   |   |   |\n|---|---|---|---|---|\n\
+             | 01 ★★ | 02 ★○ | 03 ·· | 04 ·· | 05 ·· |\n| 06 ☆▣ | 07 ?✗ |\n"
+        );
+    }
+}
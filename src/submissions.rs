@@ -0,0 +1,115 @@
+use crate::answers::{day_key, part_key};
+use crate::client::Outcome;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Guess {
+    pub answer: String,
+    pub outcome: Outcome,
+    // Seconds since the Unix epoch
+    pub time: u64,
+}
+
+// Answers submitted to the puzzle site, stored in submissions/<year>.toml:
+//
+// [[day01.part1]]
+// answer = "1234"
+// outcome = "too_high"
+// time = 1733011200
+pub struct SubmissionLog {
+    year: u16,
+    guesses: BTreeMap<String, BTreeMap<String, Vec<Guess>>>,
+}
+
+impl SubmissionLog {
+    pub fn path(year: u16) -> PathBuf {
+        PathBuf::from("submissions").join(format!("{}.toml", year))
+    }
+
+    // Loads the log of a year (a missing file is treated as an empty log)
+    pub fn load(year: u16) -> Result<Self, String> {
+        let path = Self::path(year);
+        let guesses = match fs::read_to_string(&path) {
+            Ok(content) => toml::from_str(&content).map_err(|err| format!("{}: {}", path.display(), err))?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(err) => return Err(format!("{}: {}", path.display(), err)),
+        };
+
+        Ok(SubmissionLog { year, guesses })
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let path = Self::path(self.year);
+        let content = toml::to_string(&self.guesses).map_err(|err| err.to_string())?;
+        fs::create_dir_all(path.parent().unwrap())
+            .and_then(|_| fs::write(&path, content))
+            .map_err(|err| format!("{}: {}", path.display(), err))
+    }
+
+    pub fn guesses(&self, day: u8, part: u8) -> &[Guess] {
+        self.guesses
+            .get(&day_key(day))
+            .and_then(|parts| parts.get(&part_key(part)))
+            .map_or(&[], |g| g.as_slice())
+    }
+
+    pub fn add(&mut self, day: u8, part: u8, answer: &str, outcome: Outcome) {
+        let time = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+        self.guesses
+            .entry(day_key(day))
+            .or_default()
+            .entry(part_key(part))
+            .or_default()
+            .push(Guess { answer: answer.to_string(), outcome, time });
+    }
+
+    // Reason not to submit the answer, if earlier guesses already show that it is wrong
+    pub fn refusal(&self, day: u8, part: u8, answer: &str) -> Option<String> {
+        let value = answer.parse::<i128>().ok();
+        self.guesses(day, part).iter().find_map(|guess| {
+            let guessed = guess.answer.parse::<i128>().ok();
+            match (&guess.outcome, value, guessed) {
+                (Outcome::Correct, _, _) => Some(format!("part was already solved with {}", guess.answer)),
+                (outcome, _, _) if outcome.is_wrong() && guess.answer == answer => {
+                    Some(format!("{} was already submitted: {}", answer, outcome))
+                }
+                (Outcome::TooHigh, Some(value), Some(guessed)) if value > guessed => {
+                    Some(format!("{} is higher than {}, which was too high", answer, guess.answer))
+                }
+                (Outcome::TooLow, Some(value), Some(guessed)) if value < guessed => {
+                    Some(format!("{} is lower than {}, which was too low", answer, guess.answer))
+                }
+                _ => None,
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn refuse_known_wrong_answers() {
+        let mut log = SubmissionLog { year: 2019, guesses: BTreeMap::new() };
+        log.add(1, 1, "100", Outcome::TooHigh);
+        log.add(1, 1, "20", Outcome::TooLow);
+        log.add(1, 1, "50", Outcome::Wait(30));
+
+        assert!(log.refusal(1, 1, "100").is_some());
+        assert!(log.refusal(1, 1, "101").is_some());
+        assert!(log.refusal(1, 1, "19").is_some());
+        assert_eq!(log.refusal(1, 1, "50"), None);
+        assert_eq!(log.refusal(1, 2, "100"), None);
+
+        let content = toml::to_string(&log.guesses).unwrap();
+        let log = SubmissionLog { year: 2019, guesses: toml::from_str(&content).unwrap() };
+        assert_eq!(log.guesses(1, 1).len(), 3);
+        assert_eq!(log.guesses(1, 1)[2].outcome, Outcome::Wait(30));
+    }
+}